@@ -102,6 +102,14 @@ fn benchmark_contextual_error(c: &mut Criterion) {
         });
     });
 
+    c.bench_function("create_static_contextual_error", |b| {
+        b.iter(|| {
+            let base_error = BenchError::SimpleError;
+            let error = base_error.with_static_context("Test context");
+            black_box(error)
+        });
+    });
+
     c.bench_function("contextual_error_methods", |b| {
         b.iter(|| {
             let base_error = BenchError::SimpleError;
@@ -156,6 +164,56 @@ fn benchmark_result_ext(c: &mut Criterion) {
             black_box(result)
         });
     });
+
+    c.bench_function("result_with_static_context", |b| {
+        b.iter(|| {
+            let result: Result<String, ContextualError<BenchError>> =
+                failing_operation().with_static_context("Operation context");
+            black_box(result)
+        });
+    });
+
+    c.bench_function("result_with_context_lazy", |b| {
+        let id = black_box(42);
+        b.iter(|| {
+            let result: Result<String, ContextualError<BenchError>> =
+                failing_operation()
+                    .with_context_lazy(|| format!("Operation {id} context"));
+            black_box(result)
+        });
+    });
+
+    c.bench_function("ok_with_context_eager_format", |b| {
+        let id = black_box(42);
+        b.iter(|| {
+            let result: Result<u32, ContextualError<BenchError>> =
+                Ok::<u32, std::io::Error>(1)
+                    .with_context(format!("Operation {id} context"));
+            black_box(result)
+        });
+    });
+
+    c.bench_function("ok_with_context_lazy", |b| {
+        let id = black_box(42);
+        b.iter(|| {
+            let result: Result<u32, ContextualError<BenchError>> =
+                Ok::<u32, std::io::Error>(1)
+                    .with_context_lazy(|| format!("Operation {id} context"));
+            black_box(result)
+        });
+    });
+
+    c.bench_function("option_with_context_lazy", |b| {
+        let id = black_box(42);
+        b.iter(|| {
+            let result: Result<u32, ContextualError<BenchError>> =
+                black_box(None)
+                    .with_context_lazy(BenchError::SimpleError, || {
+                        format!("Operation {id} context")
+                    });
+            black_box(result)
+        });
+    });
 }
 
 fn benchmark_memory_usage(c: &mut Criterion) {
@@ -414,38 +414,15 @@ impl Application {
         let root_cause = error.root_cause_message();
         let _ = writeln!(analysis, "Root Cause: {root_cause}");
 
-        // Specific error type detection
-        if error.contains_error::<UserServiceError>() {
-            analysis.push_str("Contains UserServiceError: Yes\n");
-            if let Some(user_error) = error.find_root::<UserServiceError>() {
+        // Business errors anywhere in the chain (types registered in `main`)
+        for link in error.chain().skip(1) {
+            if let Some(biz) = link.biz_error() {
                 let _ = writeln!(
                     analysis,
-                    "  User Error Code: {}",
-                    user_error.code()
-                );
-            }
-        }
-
-        if error.contains_error::<PaymentServiceError>() {
-            analysis.push_str("Contains PaymentServiceError: Yes\n");
-            if let Some(payment_error) =
-                error.find_root::<PaymentServiceError>()
-            {
-                let _ = writeln!(
-                    analysis,
-                    "  Payment Error Code: {}",
-                    payment_error.code()
-                );
-            }
-        }
-
-        if error.contains_error::<OrderServiceError>() {
-            analysis.push_str("Contains OrderServiceError: Yes\n");
-            if let Some(order_error) = error.find_root::<OrderServiceError>() {
-                let _ = writeln!(
-                    analysis,
-                    "  Order Error Code: {}",
-                    order_error.code()
+                    "Contains {}: {} ({})",
+                    biz.type_name().rsplit("::").next().unwrap_or_default(),
+                    biz.biz_name(),
+                    biz.biz_code()
                 );
             }
         }
@@ -463,8 +440,13 @@ impl Application {
 
         // Full error chain
         analysis.push_str("Full Error Chain:\n");
-        for (i, err_msg) in error.error_chain_messages().iter().enumerate() {
-            let _ = writeln!(analysis, "  {}. {}", i + 1, err_msg);
+        for link in error.chain() {
+            let _ = writeln!(
+                analysis,
+                "  {}. {}",
+                link.depth() + 1,
+                link.message()
+            );
         }
 
         analysis
@@ -647,5 +629,9 @@ fn print_error(prefix: &str, error: &ContextualError<AppError>) {
 }
 
 fn main() {
+    register_biz_error::<UserServiceError>();
+    register_biz_error::<PaymentServiceError>();
+    register_biz_error::<OrderServiceError>();
+
     demonstrate_error_handling();
 }
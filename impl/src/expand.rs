@@ -1,31 +1,80 @@
-use proc_macro2::TokenStream;
-use quote::quote;
+use std::{
+    fmt,
+    path::PathBuf,
+};
+
+use proc_macro2::{
+    Span,
+    TokenStream,
+};
+use quote::{
+    format_ident,
+    quote,
+};
 use syn::{
     Attribute,
     Data,
     DataEnum,
     DeriveInput,
     Error,
+    Expr,
+    ExprLit,
+    Field,
     Fields,
     Ident,
+    Lit,
     LitInt,
     LitStr,
     Meta,
+    MetaNameValue,
+    Path,
+    PathArguments,
     Result,
     Token,
+    Type,
     Variant,
+    ext::IdentExt,
     parse::{
         Parse,
         ParseStream,
     },
-    punctuated::Punctuated,
     token::Comma,
 };
 
+use crate::{
+    metadata,
+    validate,
+};
+
 pub fn derive(input: &DeriveInput) -> TokenStream {
     match try_expand(input) {
         Ok(expanded) => expanded,
-        Err(error) => error.to_compile_error(),
+        Err(error) => {
+            let compile_error = error.to_compile_error();
+            let fallback = fallback_debug_impl(input);
+            quote! {
+                #compile_error
+                #fallback
+            }
+        }
+    }
+}
+
+/// Placeholder `Debug` impl emitted next to a compile error, so that
+/// `thiserror::Error`'s `Debug` bound doesn't bury the real diagnostic
+fn fallback_debug_impl(input: &DeriveInput) -> TokenStream {
+    if !matches!(input.data, Data::Enum(_)) {
+        return quote! {};
+    }
+    let enum_name = &input.ident;
+    let enum_name_str = enum_name.to_string();
+
+    quote! {
+        impl core::fmt::Debug for #enum_name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(#enum_name_str)
+            }
+        }
     }
 }
 
@@ -45,26 +94,72 @@ fn expand_enum(
 ) -> Result<TokenStream> {
     let enum_name = &input.ident;
 
+    // Reject misplaced and duplicated helper attributes up front
+    validate::check_attributes(input, data_enum)?;
+
     // Parse configuration from #[bizconfig(...)] attribute
     let config = parse_bizconfig(&input.attrs)?;
 
     // Assign codes to all variants (explicit and automatic)
     let variants = assign_codes(&data_enum.variants, &config)?;
 
-    let biz_error_impl = generate_biz_error_impl(enum_name, &variants, &config);
+    if config.supports_const_codes() {
+        let names: Vec<_> = variants
+            .iter()
+            .map(|v| (&v.name, code_const_ident(&v.name)))
+            .collect();
+        validate::check_code_const_names(&names)?;
+    }
+
+    // Parse cross-enum conversions from #[bizmap(...)] attributes
+    let maps = parse_bizmaps(&input.attrs)?;
+
+    let inherent_impl = generate_inherent_impl(enum_name, &variants, &config);
+    let biz_error_impl =
+        generate_biz_error_impl(enum_name, &variants, &config)?;
     let debug_impl = generate_debug_impl(enum_name, &variants, &config);
+    let serde_impl = generate_serde_impl(enum_name, &variants, &config);
+    let from_impls = maps
+        .iter()
+        .map(|map| generate_bizmap_impl(enum_name, &variants, map))
+        .collect::<Result<Vec<_>>>()?;
+
+    let tracked_files = generate_tracked_files(&config);
 
     Ok(quote! {
+        #tracked_files
+        #inherent_impl
         #biz_error_impl
         #debug_impl
+        #serde_impl
+        #(#from_impls)*
     })
 }
 
-#[derive(Debug)]
 struct BizConfig {
     code_type:      String,
-    auto_start:     i64,
-    auto_increment: i64,
+    auto_start:     i128,
+    auto_increment: i128,
+    /// Implement `serde::Serialize` (`#[bizconfig(serde)]`)
+    serde:          bool,
+    /// Case of the names returned by `name()`
+    name_case:      NameCase,
+    /// Template of `doc_url()`, with `{code}` and `{name}` placeholders
+    doc_url:        Option<LitStr>,
+    /// `Severity` variant of variants without `#[bizseverity(...)]`
+    severity:       Option<Ident>,
+    /// Files read while expanding, which must trigger a rebuild when edited
+    tracked_files:  Vec<PathBuf>,
+}
+
+impl BizConfig {
+    /// Whether codes of the configured type can be expressed as associated
+    /// constants (integer primitives and `&'static str`). Explicit codes of
+    /// other types may call non-const functions, so they stay in `code()`.
+    fn supports_const_codes(&self) -> bool {
+        integer_bounds(&self.code_type).is_some() ||
+            is_static_str(&self.code_type)
+    }
 }
 
 impl Default for BizConfig {
@@ -73,72 +168,279 @@ impl Default for BizConfig {
             code_type:      "u32".to_string(),
             auto_start:     0,
             auto_increment: 1,
+            serde:          false,
+            name_case:      NameCase::Pascal,
+            doc_url:        None,
+            severity:       None,
+            tracked_files:  Vec::new(),
         }
     }
 }
 
 // Add parsing structure for bizconfig attributes
-#[derive(Debug)]
 enum BizConfigParam {
     CodeType(String),
-    AutoStart(i64),
-    AutoIncrement(i64),
+    AutoStart(i128),
+    AutoIncrement(i128),
+    Serde,
+    NameCase(NameCase),
+    DocUrl(LitStr),
+    Severity(Ident),
 }
 
+const BIZCONFIG_PARAMS: &[&str] = &[
+    "code_type",
+    "auto_start",
+    "auto_increment",
+    "serde",
+    "name_case",
+    "doc_url",
+    "severity",
+];
+
+/// `bizconfig` parameters taking a string literal
+const STRING_PARAMS: &[&str] =
+    &["code_type", "name_case", "doc_url", "severity"];
+
 impl Parse for BizConfigParam {
     fn parse(input: ParseStream) -> Result<Self> {
         let key: Ident = input.parse()?;
-        let _: Token![=] = input.parse()?;
-
         let key_str = key.to_string();
-        match key_str.as_str() {
-            "code_type" => {
-                let value: LitStr = input.parse()?;
-                Ok(BizConfigParam::CodeType(value.value()))
-            }
-            "auto_start" => {
-                let value: LitInt = input.parse()?;
-                Ok(BizConfigParam::AutoStart(value.base10_parse()?))
-            }
-            "auto_increment" => {
-                let value: LitInt = input.parse()?;
-                Ok(BizConfigParam::AutoIncrement(value.base10_parse()?))
+        if !BIZCONFIG_PARAMS.contains(&key_str.as_str()) {
+            return Err(Error::new_spanned(
+                &key,
+                validate::unknown_message(
+                    "bizconfig parameter",
+                    &key_str,
+                    BIZCONFIG_PARAMS,
+                ),
+            ));
+        }
+        if key_str == "serde" {
+            // A flag: `#[bizconfig(serde)]`
+            if input.peek(Token![=]) {
+                return Err(Error::new_spanned(
+                    &key,
+                    "serde is a flag and takes no value, e.g. \
+                     `#[bizconfig(serde)]`",
+                ));
             }
-            _ => Err(Error::new_spanned(
-                key,
-                format!("Unknown bizconfig parameter: {}", key_str),
-            )),
+            return Ok(BizConfigParam::Serde);
+        }
+        let _: Token![=] = input.parse().map_err(|e| {
+            Error::new(e.span(), format!("expected `=` after `{key_str}`"))
+        })?;
+
+        if STRING_PARAMS.contains(&key_str.as_str()) {
+            let value: LitStr = input.parse().map_err(|e| {
+                let example = match key_str.as_str() {
+                    "code_type" => "\"u16\"",
+                    "name_case" => "\"snake_case\"",
+                    "doc_url" => "\"https://errors.example.com/{code}\"",
+                    _ => "\"warning\"",
+                };
+                Error::new(
+                    e.span(),
+                    format!(
+                        "{key_str} expects a string literal, e.g. `{key_str} \
+                         = {example}`"
+                    ),
+                )
+            })?;
+            let span = value.span();
+            return match key_str.as_str() {
+                "code_type" => {
+                    parse_code_type(&value.value(), span)?;
+                    Ok(BizConfigParam::CodeType(value.value()))
+                }
+                "name_case" => NameCase::parse(&value.value(), span)
+                    .map(BizConfigParam::NameCase),
+                "doc_url" => {
+                    check_doc_url(&value)?;
+                    Ok(BizConfigParam::DocUrl(value))
+                }
+                _ => severity_variant(&value.value(), span)
+                    .map(BizConfigParam::Severity),
+            };
+        }
+
+        let value: LitInt = input.parse().map_err(|e| {
+            Error::new(
+                e.span(),
+                format!(
+                    "{key_str} expects an integer literal, e.g. `{key_str} = \
+                     100`"
+                ),
+            )
+        })?;
+        let value = value.base10_parse()?;
+        if key_str == "auto_start" {
+            Ok(BizConfigParam::AutoStart(value))
+        } else {
+            Ok(BizConfigParam::AutoIncrement(value))
         }
     }
 }
 
 struct BizConfigParams {
-    params: Punctuated<BizConfigParam, Comma>,
+    params: Vec<BizConfigParam>,
 }
 
 impl Parse for BizConfigParams {
     fn parse(input: ParseStream) -> Result<Self> {
-        Ok(BizConfigParams {
-            params: input.parse_terminated(BizConfigParam::parse, Comma)?,
+        let mut params = Vec::new();
+        let mut seen: Vec<Ident> = Vec::new();
+
+        while !input.is_empty() {
+            let key: Ident = input.fork().parse()?;
+            let param = input.parse()?;
+            if seen.contains(&key) {
+                return Err(Error::new_spanned(
+                    &key,
+                    format!("duplicate bizconfig parameter `{key}`"),
+                ));
+            }
+            seen.push(key);
+            params.push(param);
+
+            if input.is_empty() {
+                break;
+            }
+            let _: Comma = input.parse()?;
+        }
+
+        Ok(BizConfigParams { params })
+    }
+}
+
+/// Check that a configured code type names a Rust type
+fn parse_code_type(code_type: &str, span: Span) -> Result<TokenStream> {
+    syn::parse_str::<Type>(code_type)
+        .map(|ty| quote! { #ty })
+        .map_err(|_| {
+            Error::new(
+                span,
+                format!("code_type `{code_type}` is not a valid type"),
+            )
         })
+}
+
+/// A `#[bizmap(from = Source, A | B => Target, _ => Fallback)]` conversion
+struct BizMap {
+    source: Path,
+    arms:   Vec<BizMapArm>,
+}
+
+struct BizMapArm {
+    /// Source variants matched by this arm; empty for the `_` wildcard
+    sources: Vec<Ident>,
+    target:  Ident,
+}
+
+impl Parse for BizMap {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let key: Ident = input.parse()?;
+        if key != "from" {
+            return Err(Error::new_spanned(
+                key,
+                "bizmap must start with the source type: #[bizmap(from = \
+                 Type, Variant => Variant, ...)]",
+            ));
+        }
+        let _: Token![=] = input.parse()?;
+        let source: Path = input.parse()?;
+
+        let mut arms = Vec::new();
+        while !input.is_empty() {
+            let _: Comma = input.parse()?;
+            if input.is_empty() {
+                break;
+            }
+            arms.push(input.parse()?);
+        }
+
+        Ok(Self { source, arms })
+    }
+}
+
+impl Parse for BizMapArm {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut sources = Vec::new();
+        if input.peek(Token![_]) {
+            let _: Token![_] = input.parse()?;
+        } else {
+            loop {
+                sources.push(input.parse()?);
+                if !input.peek(Token![|]) {
+                    break;
+                }
+                let _: Token![|] = input.parse()?;
+            }
+        }
+        let _: Token![=>] = input.parse()?;
+        let target = input.parse()?;
+
+        Ok(Self { sources, target })
     }
 }
 
 struct VariantInfo {
-    name:   Ident,
-    code:   VariantCode,
-    fields: Fields,
+    name:        Ident,
+    code:        VariantCode,
+    fields:      Fields,
+    /// Indices of fields marked `#[biz(sensitive)]`
+    sensitive:   Vec<usize>,
+    /// Text of the variant's `///` doc comments
+    description: Option<String>,
+    /// Status from `#[bizhttp(...)]`
+    http_status: Option<u16>,
+    /// `GrpcCode` variant from `#[bizgrpc(...)]`
+    grpc_code:   Option<Ident>,
+    /// `Severity` variant from `#[bizseverity(...)]`
+    severity:    Option<Ident>,
+}
+
+impl VariantInfo {
+    fn is_sensitive(&self, index: usize) -> bool {
+        self.sensitive.contains(&index)
+    }
 }
 
 #[derive(Debug)]
 enum VariantCode {
     Explicit(TokenStream), // User-specified code
-    Auto(usize),           // Auto-assigned index
+    Auto(i128),            // Auto-assigned code value
 }
 
 fn parse_bizconfig(attrs: &[Attribute]) -> Result<BizConfig> {
     let mut config = BizConfig::default();
 
+    // Crate- and workspace-wide defaults from Cargo.toml metadata
+    let defaults = metadata::load()
+        .map_err(|message| Error::new(Span::call_site(), message))?;
+    if let Some(code_type) = defaults.code_type {
+        parse_code_type(&code_type, Span::call_site())?;
+        config.code_type = code_type;
+    }
+    if let Some(auto_start) = defaults.auto_start {
+        config.auto_start = auto_start.into();
+    }
+    if let Some(auto_increment) = defaults.auto_increment {
+        config.auto_increment = auto_increment.into();
+    }
+    if let Some(name_case) = defaults.name_case {
+        config.name_case = NameCase::parse(&name_case, Span::call_site())?;
+    }
+    if let Some(doc_url) = defaults.doc_url {
+        let doc_url = LitStr::new(&doc_url, Span::call_site());
+        check_doc_url(&doc_url)?;
+        config.doc_url = Some(doc_url);
+    }
+    if let Some(severity) = defaults.severity {
+        config.severity = Some(severity_variant(&severity, Span::call_site())?);
+    }
+    config.tracked_files = defaults.manifests;
+
     for attr in attrs {
         if attr.path().is_ident("bizconfig") {
             parse_bizconfig_content(attr, &mut config)?;
@@ -169,6 +471,18 @@ fn parse_bizconfig_content(
                     BizConfigParam::AutoIncrement(value) => {
                         config.auto_increment = value;
                     }
+                    BizConfigParam::Serde => {
+                        config.serde = true;
+                    }
+                    BizConfigParam::NameCase(value) => {
+                        config.name_case = value;
+                    }
+                    BizConfigParam::DocUrl(value) => {
+                        config.doc_url = Some(value);
+                    }
+                    BizConfigParam::Severity(value) => {
+                        config.severity = Some(value);
+                    }
                 }
             }
         }
@@ -186,12 +500,20 @@ fn parse_bizconfig_content(
     Ok(())
 }
 
+fn parse_bizmaps(attrs: &[Attribute]) -> Result<Vec<BizMap>> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("bizmap"))
+        .map(|attr| attr.parse_args())
+        .collect()
+}
+
 fn assign_codes(
     variants: &syn::punctuated::Punctuated<Variant, syn::token::Comma>,
-    _config: &BizConfig,
+    config: &BizConfig,
 ) -> Result<Vec<VariantInfo>> {
     let mut result = Vec::new();
-    let mut auto_counter = 0usize;
+    let mut auto_counter = 0i128;
 
     for variant in variants {
         let code = if let Some(explicit_code) =
@@ -199,21 +521,154 @@ fn assign_codes(
         {
             VariantCode::Explicit(explicit_code)
         } else {
-            let auto_code = VariantCode::Auto(auto_counter);
+            let value = auto_code_value(variant, auto_counter, config)?;
             auto_counter += 1;
-            auto_code
+            VariantCode::Auto(value)
         };
 
         result.push(VariantInfo {
             name: variant.ident.clone(),
             code,
             fields: variant.fields.clone(),
+            sensitive: sensitive_field_indices(&variant.fields)?,
+            description: extract_doc_comment(&variant.attrs),
+            http_status: extract_bizhttp_attr(&variant.attrs)?,
+            grpc_code: extract_bizgrpc_attr(&variant.attrs)?,
+            severity: extract_bizseverity_attr(&variant.attrs)?,
         });
     }
 
     Ok(result)
 }
 
+/// Compute `auto_start + index * auto_increment` and check that it fits the
+/// configured code type
+fn auto_code_value(
+    variant: &Variant,
+    index: i128,
+    config: &BizConfig,
+) -> Result<i128> {
+    let code_type = config.code_type.as_str();
+    if code_type != "String" &&
+        !code_type.contains("str") &&
+        integer_bounds(code_type).is_none()
+    {
+        return Err(Error::new_spanned(
+            &variant.ident,
+            format!(
+                "cannot auto-assign a code of type `{code_type}` to `{}`, add \
+                 an explicit #[bizcode(...)]",
+                variant.ident
+            ),
+        ));
+    }
+
+    let value = index
+        .checked_mul(config.auto_increment)
+        .and_then(|offset| config.auto_start.checked_add(offset));
+    let in_range = |value: i128| {
+        integer_bounds(code_type)
+            .is_none_or(|(min, max)| (min..=max).contains(&value))
+    };
+
+    match value {
+        Some(value) if in_range(value) => Ok(value),
+        _ => {
+            let computed = value.map_or_else(
+                || "a value beyond i128".to_string(),
+                |value| value.to_string(),
+            );
+            let (min, max) =
+                integer_bounds(code_type).unwrap_or((i128::MIN, i128::MAX));
+            Err(Error::new_spanned(
+                &variant.ident,
+                format!(
+                    "auto-assigned code for `{}` overflows `{code_type}`: {} \
+                     + {index} * {} is {computed}, outside {min}..={max}",
+                    variant.ident, config.auto_start, config.auto_increment
+                ),
+            ))
+        }
+    }
+}
+
+/// Value range of integer code types; `None` for non-integer types
+///
+/// The proc macro can't see the target, so `usize` and `isize` get their
+/// 32-bit range, which fits both 32- and 64-bit targets.
+fn integer_bounds(code_type: &str) -> Option<(i128, i128)> {
+    let bounds = match code_type {
+        "u8" => (0, u8::MAX.into()),
+        "u16" => (0, u16::MAX.into()),
+        "u32" | "usize" => (0, u32::MAX.into()),
+        "u64" => (0, u64::MAX.into()),
+        // Auto codes are computed in i128, so u128 is limited to its range
+        "u128" => (0, i128::MAX),
+        "i8" => (i8::MIN.into(), i8::MAX.into()),
+        "i16" => (i16::MIN.into(), i16::MAX.into()),
+        "i32" | "isize" => (i32::MIN.into(), i32::MAX.into()),
+        "i64" => (i64::MIN.into(), i64::MAX.into()),
+        "i128" => (i128::MIN, i128::MAX),
+        _ => return None,
+    };
+    Some(bounds)
+}
+
+fn sensitive_field_indices(fields: &Fields) -> Result<Vec<usize>> {
+    let mut sensitive = Vec::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        for attr in &field.attrs {
+            if !attr.path().is_ident("biz") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("sensitive") {
+                    sensitive.push(index);
+                    Ok(())
+                } else {
+                    let path = &meta.path;
+                    let name = quote! { #path }.to_string().replace(' ', "");
+                    Err(meta.error(validate::unknown_message(
+                        "biz field attribute",
+                        &name,
+                        &["sensitive"],
+                    )))
+                }
+            })?;
+        }
+    }
+
+    Ok(sensitive)
+}
+
+fn extract_doc_comment(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(MetaNameValue {
+                value:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(doc), ..
+                    }),
+                ..
+            }) => Some(doc.value()),
+            _ => None,
+        })
+        .collect();
+
+    // `/// text` becomes `#[doc = " text"]`; drop the conventional space
+    let text = lines
+        .iter()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let text = text.trim();
+
+    (!text.is_empty()).then(|| text.to_string())
+}
+
 fn extract_bizcode_attr(attrs: &[Attribute]) -> Result<Option<TokenStream>> {
     for attr in attrs {
         if attr.path().is_ident("bizcode") {
@@ -225,37 +680,216 @@ fn extract_bizcode_attr(attrs: &[Attribute]) -> Result<Option<TokenStream>> {
 
 fn parse_bizcode_value(attr: &Attribute) -> Result<TokenStream> {
     match &attr.meta {
+        Meta::List(meta_list) if meta_list.tokens.is_empty() => {
+            Err(Error::new_spanned(
+                attr,
+                "#[bizcode()] is missing a code, e.g. #[bizcode(4001)]",
+            ))
+        }
         Meta::List(meta_list) => {
             // Return the tokens as-is, let the compiler handle type checking
             Ok(meta_list.tokens.clone())
         }
-        _ => Err(Error::new_spanned(
+        Meta::NameValue(name_value) => {
+            let value = &name_value.value;
+            Err(Error::new_spanned(
+                attr,
+                format!(
+                    "bizcode attribute must be a list: use #[bizcode({})]",
+                    quote! { #value }
+                ),
+            ))
+        }
+        Meta::Path(_) => Err(Error::new_spanned(
             attr,
             "bizcode attribute must be a list: #[bizcode(value)]",
         )),
     }
 }
 
-fn generate_biz_error_impl(
+fn extract_bizhttp_attr(attrs: &[Attribute]) -> Result<Option<u16>> {
+    let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("bizhttp"))
+    else {
+        return Ok(None);
+    };
+    let status: LitInt = attr.parse_args().map_err(|e| {
+        Error::new(
+            e.span(),
+            "bizhttp expects an HTTP status code, e.g. #[bizhttp(404)]",
+        )
+    })?;
+    match status.base10_parse::<u16>() {
+        Ok(value @ 100..=599) => Ok(Some(value)),
+        _ => Err(Error::new_spanned(
+            &status,
+            format!("HTTP status `{status}` is not between 100 and 599"),
+        )),
+    }
+}
+
+/// gRPC codes accepted by `#[bizgrpc(...)]`, i.e. every `bizerror::GrpcCode`
+/// but `Ok`
+const GRPC_CODES: &[&str] = &[
+    "cancelled",
+    "unknown",
+    "invalid_argument",
+    "deadline_exceeded",
+    "not_found",
+    "already_exists",
+    "permission_denied",
+    "resource_exhausted",
+    "failed_precondition",
+    "aborted",
+    "out_of_range",
+    "unimplemented",
+    "internal",
+    "unavailable",
+    "data_loss",
+    "unauthenticated",
+];
+
+/// Parse `#[bizgrpc(not_found)]` into the `GrpcCode` variant `NotFound`
+fn extract_bizgrpc_attr(attrs: &[Attribute]) -> Result<Option<Ident>> {
+    let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("bizgrpc"))
+    else {
+        return Ok(None);
+    };
+    let code: Ident = attr.parse_args().map_err(|e| {
+        Error::new(
+            e.span(),
+            "bizgrpc expects a gRPC status code, e.g. #[bizgrpc(not_found)]",
+        )
+    })?;
+    let name = code.to_string();
+    if !GRPC_CODES.contains(&name.as_str()) {
+        return Err(Error::new_spanned(
+            &code,
+            validate::unknown_message("gRPC status code", &name, GRPC_CODES),
+        ));
+    }
+    Ok(Some(Ident::new(&snake_to_pascal(&name), code.span())))
+}
+
+/// Severities accepted by `#[bizseverity(...)]` and `severity = "..."`, i.e.
+/// every `bizerror::Severity`
+const SEVERITIES: &[&str] = &["info", "warning", "error", "critical"];
+
+/// Parse `#[bizseverity(warning)]` into the `Severity` variant `Warning`
+fn extract_bizseverity_attr(attrs: &[Attribute]) -> Result<Option<Ident>> {
+    let Some(attr) = attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("bizseverity"))
+    else {
+        return Ok(None);
+    };
+    let severity: Ident = attr.parse_args().map_err(|e| {
+        Error::new(
+            e.span(),
+            "bizseverity expects a severity, e.g. #[bizseverity(warning)]",
+        )
+    })?;
+    severity_variant(&severity.to_string(), severity.span()).map(Some)
+}
+
+/// The `Severity` variant of a severity written in snake case
+fn severity_variant(severity: &str, span: Span) -> Result<Ident> {
+    if !SEVERITIES.contains(&severity) {
+        return Err(Error::new(
+            span,
+            validate::unknown_message("severity", severity, SEVERITIES),
+        ));
+    }
+    Ok(Ident::new(&snake_to_pascal(severity), span))
+}
+
+fn snake_to_pascal(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_ascii_uppercase().to_string() + chars.as_str()
+            })
+        })
+        .collect()
+}
+
+fn generate_inherent_impl(
     enum_name: &Ident,
     variants: &[VariantInfo],
     config: &BizConfig,
 ) -> TokenStream {
-    let code_type = config.code_type.parse().unwrap_or_else(|_| quote! { u32 });
+    if !config.supports_const_codes() {
+        return quote! {};
+    }
+
+    let code_type = code_type_tokens(config);
+
+    let code_consts = variants.iter().map(|v| {
+        let const_name = code_const_ident(&v.name);
+        let code_value = generate_code_value(&v.code, config);
+        let doc = format!("Business error code of [`{enum_name}::{}`]", v.name);
+
+        quote! {
+            #[doc = #doc]
+            pub const #const_name: #code_type = #code_value;
+        }
+    });
 
     let code_arms = variants.iter().map(|v| {
         let variant_name = &v.name;
-        let code_value = generate_code_value(&v.code, config);
+        let const_name = code_const_ident(&v.name);
         let pattern = make_pattern(&v.fields);
 
         quote! {
-            Self::#variant_name #pattern => #code_value,
+            Self::#variant_name #pattern => Self::#const_name,
         }
     });
 
+    quote! {
+        impl #enum_name {
+            #(#code_consts)*
+
+            /// Get the business error code of this variant in const contexts
+            pub const fn code_of_kind(&self) -> #code_type {
+                match self {
+                    #(#code_arms)*
+                }
+            }
+        }
+    }
+}
+
+fn generate_biz_error_impl(
+    enum_name: &Ident,
+    variants: &[VariantInfo],
+    config: &BizConfig,
+) -> Result<TokenStream> {
+    let code_type = code_type_tokens(config);
+
+    let code_body = if config.supports_const_codes() {
+        // Delegate to the associated constants emitted in the inherent impl
+        quote! { self.code_of_kind() }
+    } else {
+        let code_arms = variants.iter().map(|v| {
+            let variant_name = &v.name;
+            let code_value = generate_code_value(&v.code, config);
+            let pattern = make_pattern(&v.fields);
+
+            quote! {
+                Self::#variant_name #pattern => #code_value,
+            }
+        });
+
+        quote! {
+            match self {
+                #(#code_arms)*
+            }
+        }
+    };
+
     let name_arms = variants.iter().map(|v| {
         let variant_name = &v.name;
-        let name_str = variant_name.to_string();
+        let name_str = config.name_case.apply(&variant_name.to_string());
         let pattern = make_pattern(&v.fields);
 
         quote! {
@@ -263,14 +897,20 @@ fn generate_biz_error_impl(
         }
     });
 
-    quote! {
+    let fields_fn = generate_fields_fn(variants);
+    let is_sensitive_fn = generate_is_sensitive_fn(variants);
+    let description_fn = generate_description_fn(variants);
+    let http_status_fn = generate_http_status_fn(variants);
+    let grpc_code_fn = generate_grpc_code_fn(variants);
+    let severity_fn = generate_severity_fn(variants, config);
+    let doc_url_fn = generate_doc_url_fn(variants, config)?;
+
+    Ok(quote! {
         impl bizerror::BizError for #enum_name {
             type CodeType = #code_type;
 
             fn code(&self) -> Self::CodeType {
-                match self {
-                    #(#code_arms)*
-                }
+                #code_body
             }
 
             fn name(&self) -> &str {
@@ -279,9 +919,588 @@ fn generate_biz_error_impl(
                 }
             }
 
+            #fields_fn
+
+            #is_sensitive_fn
+
+            #description_fn
+
+            #http_status_fn
+
+            #grpc_code_fn
+
+            #severity_fn
+
+            #doc_url_fn
+
             // msg() uses default implementation: self.to_string()
         }
+    })
+}
+
+/// `serde::Serialize` as `{code, name, message, fields}` and
+/// `FromBizCode` for unit variants, only with `#[bizconfig(serde)]`
+fn generate_serde_impl(
+    enum_name: &Ident,
+    variants: &[VariantInfo],
+    config: &BizConfig,
+) -> TokenStream {
+    if !config.serde {
+        return quote! {};
+    }
+
+    let unit_variants: Vec<_> = variants
+        .iter()
+        .filter(|v| matches!(v.fields, Fields::Unit))
+        .map(|v| &v.name)
+        .collect();
+
+    quote! {
+        bizerror::__bizerror_serde! {
+            impl bizerror::__private::serde::Serialize for #enum_name {
+                fn serialize<S>(
+                    &self,
+                    serializer: S,
+                ) -> core::result::Result<S::Ok, S::Error>
+                where
+                    S: bizerror::__private::serde::Serializer,
+                {
+                    bizerror::__private::serialize_biz_error(self, serializer)
+                }
+            }
+
+            impl bizerror::FromBizCode for #enum_name {
+                fn from_biz_code(
+                    code: &bizerror::BizCode,
+                ) -> core::option::Option<Self> {
+                    #(
+                        if bizerror::DynBizError::biz_code(&Self::#unit_variants)
+                            == *code
+                        {
+                            return core::option::Option::Some(
+                                Self::#unit_variants,
+                            );
+                        }
+                    )*
+                    let _ = code;
+                    core::option::Option::None
+                }
+            }
+        }
+    }
+}
+
+fn generate_fields_fn(variants: &[VariantInfo]) -> TokenStream {
+    let has_fields = variants
+        .iter()
+        .any(|v| v.fields.iter().any(|field| !is_source_field(field)));
+    if !has_fields {
+        // Keep the trait's default (no fields)
+        return quote! {};
+    }
+
+    let field_arms = variants.iter().map(|v| {
+        let variant_name = &v.name;
+        // Exposed fields; sensitive ones are never bound, only named
+        let exposed: Vec<_> = v
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| !is_source_field(field))
+            .map(|(index, field)| {
+                let key = field.ident.as_ref().map_or_else(
+                    || index.to_string(),
+                    |ident| ident.unraw().to_string(),
+                );
+                let binding = (!v.is_sensitive(index))
+                    .then(|| format_ident!("__biz_field_{}", index));
+                (index, field, key, binding)
+            })
+            .collect();
+
+        let pattern = match &v.fields {
+            Fields::Unit => quote! {},
+            Fields::Named(_) => {
+                let bindings =
+                    exposed.iter().filter_map(|(_, field, _, binding)| {
+                        let ident = &field.ident;
+                        binding.as_ref().map(|binding| quote! { #ident: #binding })
+                    });
+                quote! { { #(#bindings,)* .. } }
+            }
+            Fields::Unnamed(unnamed) => {
+                let bindings = (0..unnamed.unnamed.len()).map(|index| {
+                    exposed
+                        .iter()
+                        .find_map(|(i, _, _, binding)| {
+                            binding.as_ref().filter(|_| *i == index)
+                        })
+                        .map_or_else(|| quote! { _ }, |binding| quote! { #binding })
+                });
+                quote! { ( #(#bindings),* ) }
+            }
+        };
+
+        let pushes = exposed.iter().map(|(_, _, key, binding)| match binding {
+            Some(binding) => quote! {
+                if let Some(value) =
+                    (&bizerror::__private::FieldProbe(#binding)).biz_field_value()
+                {
+                    fields.push((bizerror::__private::Cow::Borrowed(#key), value));
+                }
+            },
+            None => quote! {
+                fields.push((
+                    bizerror::__private::Cow::Borrowed(#key),
+                    bizerror::BizFieldValue::Redacted,
+                ));
+            },
+        });
+
+        quote! {
+            Self::#variant_name #pattern => {
+                #(#pushes)*
+            }
+        }
+    });
+
+    // `fields()` only exists when bizerror is built with `alloc`
+    quote! {
+        bizerror::__bizerror_alloc! {
+            fn fields(
+                &self,
+            ) -> bizerror::__private::Vec<(
+                bizerror::__private::Cow<'static, str>,
+                bizerror::BizFieldValue,
+            )> {
+                #[allow(unused_imports)]
+                use bizerror::__private::{
+                    ViaFallback as _,
+                    ViaToBizFieldValue as _,
+                };
+
+                let mut fields = bizerror::__private::Vec::new();
+                match self {
+                    #(#field_arms)*
+                }
+                fields
+            }
+        }
+    }
+}
+
+fn generate_is_sensitive_fn(variants: &[VariantInfo]) -> TokenStream {
+    let sensitive_patterns: Vec<_> = variants
+        .iter()
+        .filter(|v| !v.sensitive.is_empty())
+        .map(|v| {
+            let variant_name = &v.name;
+            let pattern = make_pattern(&v.fields);
+            quote! { Self::#variant_name #pattern }
+        })
+        .collect();
+
+    if sensitive_patterns.is_empty() {
+        return quote! {};
+    }
+
+    quote! {
+        fn is_sensitive(&self) -> bool {
+            matches!(self, #(#sensitive_patterns)|*)
+        }
+    }
+}
+
+fn generate_bizmap_impl(
+    enum_name: &Ident,
+    variants: &[VariantInfo],
+    map: &BizMap,
+) -> Result<TokenStream> {
+    let source_type = &map.source;
+    let source_pattern = expr_style_path(source_type);
+
+    let arms = map
+        .arms
+        .iter()
+        .map(|arm| {
+            let target = variants
+                .iter()
+                .find(|v| v.name == arm.target)
+                .ok_or_else(|| {
+                    Error::new_spanned(
+                        &arm.target,
+                        format!("no variant `{}` in `{enum_name}`", arm.target),
+                    )
+                })?;
+            let target_name = &target.name;
+            let construct = match &target.fields {
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                    quote! { Self::#target_name(core::convert::Into::into(source)) }
+                }
+                Fields::Named(fields) if fields.named.len() == 1 => {
+                    let ident = &fields.named[0].ident;
+                    quote! {
+                        Self::#target_name {
+                            #ident: core::convert::Into::into(source),
+                        }
+                    }
+                }
+                _ => return Err(bizmap_target_error(&arm.target)),
+            };
+
+            let pattern = if arm.sources.is_empty() {
+                quote! { _ }
+            } else {
+                let sources = arm.sources.iter();
+                quote! { #(#source_pattern::#sources { .. })|* }
+            };
+
+            Ok(quote! { #pattern => #construct, })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl core::convert::From<#source_type> for #enum_name {
+            fn from(source: #source_type) -> Self {
+                match &source {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+/// Write the generic arguments of `path` with turbofish, e.g.
+/// `Wrapper::<u8>`, as a pattern requires
+fn expr_style_path(path: &Path) -> Path {
+    let mut path = path.clone();
+    for segment in &mut path.segments {
+        if let PathArguments::AngleBracketed(arguments) = &mut segment.arguments
+        {
+            arguments.colon2_token.get_or_insert_with(Default::default);
+        }
+    }
+    path
+}
+
+fn bizmap_target_error(target: &Ident) -> Error {
+    Error::new_spanned(
+        target,
+        format!(
+            "bizmap target `{target}` must hold the original error in its \
+             only field, e.g. `{target}(#[source] SourceError)`"
+        ),
+    )
+}
+
+fn generate_description_fn(variants: &[VariantInfo]) -> TokenStream {
+    if variants.iter().all(|v| v.description.is_none()) {
+        return quote! {};
+    }
+
+    let description_arms = variants.iter().map(|v| {
+        let variant_name = &v.name;
+        let pattern = make_pattern(&v.fields);
+        let description = match &v.description {
+            Some(text) => quote! { Some(#text) },
+            None => quote! { None },
+        };
+
+        quote! {
+            Self::#variant_name #pattern => #description,
+        }
+    });
+
+    quote! {
+        fn description(&self) -> Option<&'static str> {
+            match self {
+                #(#description_arms)*
+            }
+        }
+    }
+}
+
+fn generate_http_status_fn(variants: &[VariantInfo]) -> TokenStream {
+    if variants.iter().all(|v| v.http_status.is_none()) {
+        return quote! {};
+    }
+
+    let status_arms = variants.iter().map(|v| {
+        let variant_name = &v.name;
+        let pattern = make_pattern(&v.fields);
+        let status = match v.http_status {
+            Some(status) => quote! { Some(#status) },
+            None => quote! { None },
+        };
+
+        quote! {
+            Self::#variant_name #pattern => #status,
+        }
+    });
+
+    quote! {
+        fn http_status(&self) -> Option<u16> {
+            match self {
+                #(#status_arms)*
+            }
+        }
+    }
+}
+
+fn generate_grpc_code_fn(variants: &[VariantInfo]) -> TokenStream {
+    if variants.iter().all(|v| v.grpc_code.is_none()) {
+        return quote! {};
+    }
+
+    let code_arms = variants.iter().map(|v| {
+        let variant_name = &v.name;
+        let pattern = make_pattern(&v.fields);
+        let code = match &v.grpc_code {
+            Some(code) => quote! { Some(bizerror::GrpcCode::#code) },
+            None => quote! { None },
+        };
+
+        quote! {
+            Self::#variant_name #pattern => #code,
+        }
+    });
+
+    quote! {
+        fn grpc_code(&self) -> Option<bizerror::GrpcCode> {
+            match self {
+                #(#code_arms)*
+            }
+        }
+    }
+}
+
+fn generate_severity_fn(
+    variants: &[VariantInfo],
+    config: &BizConfig,
+) -> TokenStream {
+    if config.severity.is_none() &&
+        variants.iter().all(|v| v.severity.is_none())
+    {
+        return quote! {};
+    }
+
+    let default = config
+        .severity
+        .clone()
+        .unwrap_or_else(|| Ident::new("Error", Span::call_site()));
+    let severity_arms = variants.iter().map(|v| {
+        let variant_name = &v.name;
+        let pattern = make_pattern(&v.fields);
+        let severity = v.severity.as_ref().unwrap_or(&default);
+
+        quote! {
+            Self::#variant_name #pattern => bizerror::Severity::#severity,
+        }
+    });
+
+    quote! {
+        fn severity(&self) -> bizerror::Severity {
+            match self {
+                #(#severity_arms)*
+            }
+        }
+    }
+}
+
+/// `doc_url()` with the template filled in for every variant, which needs
+/// the codes as literals when the template uses `{code}`
+fn generate_doc_url_fn(
+    variants: &[VariantInfo],
+    config: &BizConfig,
+) -> Result<TokenStream> {
+    let Some(template) = &config.doc_url else {
+        return Ok(quote! {});
+    };
+    let template_str = template.value();
+
+    let url_arms = variants
+        .iter()
+        .map(|v| {
+            let variant_name = &v.name;
+            let pattern = make_pattern(&v.fields);
+            let mut url = template_str.replace(
+                "{name}",
+                &config.name_case.apply(&v.name.to_string()),
+            );
+            if url.contains("{code}") {
+                let code = literal_code(&v.code, config).ok_or_else(|| {
+                    Error::new_spanned(
+                        variant_name,
+                        format!(
+                            "doc_url needs a literal code to fill `{{code}}`, \
+                             but the code of `{variant_name}` is an expression"
+                        ),
+                    )
+                })?;
+                url = url.replace("{code}", &code.to_string());
+            }
+
+            Ok(quote! {
+                Self::#variant_name #pattern => #url,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        fn doc_url(&self) -> Option<&'static str> {
+            Some(match self {
+                #(#url_arms)*
+            })
+        }
+    })
+}
+
+/// Reject placeholders other than `{code}` and `{name}` in a `doc_url`
+fn check_doc_url(template: &LitStr) -> Result<()> {
+    const PLACEHOLDERS: &[&str] = &["code", "name"];
+
+    let value = template.value();
+    let mut rest = value.as_str();
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}') else {
+            return Err(Error::new(
+                template.span(),
+                "doc_url has an unclosed `{`",
+            ));
+        };
+        let placeholder = &rest[start + 1..start + end];
+        if !PLACEHOLDERS.contains(&placeholder) {
+            return Err(Error::new(
+                template.span(),
+                validate::unknown_message(
+                    "doc_url placeholder",
+                    placeholder,
+                    PLACEHOLDERS,
+                ),
+            ));
+        }
+        rest = &rest[start + end + 1..];
     }
+    Ok(())
+}
+
+/// `const _: &[u8] = include_bytes!(...)` for every file the expansion read,
+/// so that Cargo rebuilds the crate when one of them changes
+fn generate_tracked_files(config: &BizConfig) -> TokenStream {
+    let paths = config.tracked_files.iter().filter_map(|path| path.to_str());
+
+    quote! {
+        #(const _: &[u8] = include_bytes!(#paths);)*
+    }
+}
+
+/// Case of the names returned by `name()`, set with `name_case`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NameCase {
+    /// The variant name as written
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+}
+
+const NAME_CASES: &[&str] = &[
+    "PascalCase",
+    "camelCase",
+    "snake_case",
+    "SCREAMING_SNAKE_CASE",
+    "kebab-case",
+];
+
+impl NameCase {
+    fn parse(name_case: &str, span: Span) -> Result<Self> {
+        match name_case {
+            "PascalCase" => Ok(Self::Pascal),
+            "camelCase" => Ok(Self::Camel),
+            "snake_case" => Ok(Self::Snake),
+            "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnake),
+            "kebab-case" => Ok(Self::Kebab),
+            _ => Err(Error::new(
+                span,
+                validate::unknown_message("name_case", name_case, NAME_CASES),
+            )),
+        }
+    }
+
+    fn apply(self, variant_name: &str) -> String {
+        let screaming = || to_screaming_snake_case(variant_name);
+        match self {
+            Self::Pascal => variant_name.to_string(),
+            Self::Camel => {
+                let snake = screaming().to_lowercase();
+                let mut words = snake.split('_');
+                let first = words.next().unwrap_or_default().to_string();
+                first + &snake_to_pascal(&words.collect::<Vec<_>>().join("_"))
+            }
+            Self::Snake => screaming().to_lowercase(),
+            Self::ScreamingSnake => screaming(),
+            Self::Kebab => screaming().to_lowercase().replace('_', "-"),
+        }
+    }
+}
+
+/// A code known at expansion time, for `doc_url`
+enum LiteralCode {
+    Int(i128),
+    Str(String),
+}
+
+impl fmt::Display for LiteralCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Str(value) => f.write_str(value),
+        }
+    }
+}
+
+/// The code of a variant if it is an integer or string literal
+fn literal_code(code: &VariantCode, config: &BizConfig) -> Option<LiteralCode> {
+    let tokens = match code {
+        VariantCode::Auto(value)
+            if integer_bounds(&config.code_type).is_some() =>
+        {
+            return Some(LiteralCode::Int(*value));
+        }
+        // Auto codes of string types are the number as a string
+        VariantCode::Auto(value) => {
+            return Some(LiteralCode::Str(value.to_string()));
+        }
+        VariantCode::Explicit(tokens) => tokens,
+    };
+
+    let (negative, expr) = match syn::parse2::<Expr>(tokens.clone()).ok()? {
+        Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => (true, *expr),
+        expr => (false, expr),
+    };
+    let Expr::Lit(ExprLit { lit, .. }) = expr else {
+        return None;
+    };
+    match lit {
+        Lit::Int(int) => {
+            let value: i128 = int.base10_parse().ok()?;
+            Some(LiteralCode::Int(if negative { -value } else { value }))
+        }
+        Lit::Str(string) if !negative => Some(LiteralCode::Str(string.value())),
+        _ => None,
+    }
+}
+
+/// Whether thiserror treats this field as the error source
+fn is_source_field(field: &Field) -> bool {
+    field.attrs.iter().any(|attr| {
+        attr.path().is_ident("from") || attr.path().is_ident("source")
+    }) || field.ident.as_ref().is_some_and(|ident| ident == "source")
 }
 
 fn generate_debug_impl(
@@ -295,14 +1514,20 @@ fn generate_debug_impl(
         let variant_name = &v.name;
         let variant_name_str = variant_name.to_string();
         let pattern = make_pattern(&v.fields);
+        // The message may embed sensitive field values via `Display`
+        let message = if v.sensitive.is_empty() {
+            quote! { &bizerror::__private::DisplayAsDebug(self) }
+        } else {
+            quote! { &"<redacted>" }
+        };
 
         quote! {
             Self::#variant_name #pattern => {
                 let mut debug_struct = f.debug_struct(#enum_name_str);
                 debug_struct.field("variant", &#variant_name_str);
                 debug_struct.field("code", &self.code());
-                debug_struct.field("message", &self.to_string());
-                if let Some(source) = std::error::Error::source(self) {
+                debug_struct.field("message", #message);
+                if let Some(source) = core::error::Error::source(self) {
                     debug_struct.field("source", &source);
                 }
                 debug_struct.finish()
@@ -311,8 +1536,8 @@ fn generate_debug_impl(
     });
 
     quote! {
-        impl std::fmt::Debug for #enum_name {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        impl core::fmt::Debug for #enum_name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 match self {
                     #(#debug_arms)*
                 }
@@ -334,35 +1559,78 @@ fn generate_code_value(code: &VariantCode, config: &BizConfig) -> TokenStream {
                 tokens.clone()
             }
         }
-        VariantCode::Auto(index) => {
-            // For auto-generated codes, we need to generate the appropriate
-            // literal
-            let value =
-                config.auto_start + (*index as i64 * config.auto_increment);
-
+        VariantCode::Auto(value) => {
+            // Auto-assigned codes were range checked in `auto_code_value`, so
+            // emit a literal typed with the target type
             match config.code_type.as_str() {
-                "String" => quote! { #value.to_string() },
+                "String" => {
+                    let value_str = value.to_string();
+                    quote! { #value_str.to_string() }
+                }
                 t if t.contains("str") => {
                     let value_str = value.to_string();
                     quote! { #value_str }
                 }
-                "i64" => quote! { #value }, /* i64 is the native type, no
-                                              * cast needed */
-                _ => {
-                    // For all other numeric types, cast to the target type
-                    // This handles u8, u16, u32, u64, u128, i8, i16, i32, i128,
-                    // etc.
-                    let target_type = config
-                        .code_type
-                        .parse()
-                        .unwrap_or_else(|_| quote! { u32 });
-                    quote! { #value as #target_type }
+                t => {
+                    let literal = LitInt::new(
+                        &format!("{}{t}", value.unsigned_abs()),
+                        Span::call_site(),
+                    );
+                    if *value < 0 {
+                        quote! { -#literal }
+                    } else {
+                        quote! { #literal }
+                    }
                 }
             }
         }
     }
 }
 
+/// Whether `code_type` spells `&'static str`, whatever the whitespace
+fn is_static_str(code_type: &str) -> bool {
+    code_type.split_whitespace().collect::<String>() == "&'staticstr"
+}
+
+fn code_type_tokens(config: &BizConfig) -> TokenStream {
+    config.code_type.parse().unwrap_or_else(|_| quote! { u32 })
+}
+
+/// Name of the associated code constant: `ValidationError` ->
+/// `VALIDATION_ERROR_CODE`
+fn code_const_ident(variant_name: &Ident) -> Ident {
+    let name = format!(
+        "{}_CODE",
+        to_screaming_snake_case(&variant_name.to_string())
+    );
+    Ident::new(&name, variant_name.span())
+}
+
+fn to_screaming_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut result = String::with_capacity(name.len() + 4);
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower =
+                chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            // Word boundary: `fooBar`, `foo1Bar`, or the end of an acronym
+            // as in `HTTPError`
+            if prev != '_' &&
+                (prev.is_lowercase() ||
+                    prev.is_ascii_digit() ||
+                    (prev.is_uppercase() && next_is_lower))
+            {
+                result.push('_');
+            }
+        }
+        result.extend(c.to_uppercase());
+    }
+
+    result
+}
+
 fn make_pattern(fields: &Fields) -> TokenStream {
     match fields {
         // Unit variant: Timeout
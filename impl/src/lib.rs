@@ -1,4 +1,6 @@
 mod expand;
+mod metadata;
+mod validate;
 
 use proc_macro::TokenStream;
 use syn::{
@@ -6,7 +8,10 @@ use syn::{
     parse_macro_input,
 };
 
-#[proc_macro_derive(BizError, attributes(bizcode, bizconfig))]
+#[proc_macro_derive(
+    BizError,
+    attributes(bizcode, bizconfig, bizgrpc, bizhttp, bizmap, bizseverity, biz)
+)]
 pub fn derive_error(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     expand::derive(&input).into()
@@ -0,0 +1,379 @@
+//! `bizconfig` defaults shared across a crate or workspace
+//!
+//! Defaults are read from `[workspace.metadata.bizerror]` in the workspace
+//! manifest and `[package.metadata.bizerror]` in the crate manifest (located
+//! via `CARGO_MANIFEST_DIR`). The workspace manifest is the one named by
+//! `package.workspace`, or else the closest parent manifest with a
+//! `[workspace]` table. Package settings override workspace settings, and a
+//! per-enum `#[bizconfig(...)]` overrides both.
+
+use std::{
+    env,
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use toml::{
+    Table,
+    Value,
+};
+
+use crate::validate;
+
+const SUPPORTED_KEYS: &[&str] = &[
+    "code_type",
+    "auto_start",
+    "auto_increment",
+    "name_case",
+    "doc_url",
+    "severity",
+];
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ManifestDefaults {
+    pub code_type:      Option<String>,
+    pub auto_start:     Option<i64>,
+    pub auto_increment: Option<i64>,
+    pub name_case:      Option<String>,
+    pub doc_url:        Option<String>,
+    pub severity:       Option<String>,
+    /// Every manifest read, so the expansion can make Cargo track them
+    pub manifests:      Vec<PathBuf>,
+}
+
+/// A parsed `Cargo.toml` and the path it was read from
+struct Manifest {
+    path:  PathBuf,
+    table: Table,
+}
+
+impl Manifest {
+    fn dir(&self) -> &Path {
+        self.path.parent().unwrap_or_else(|| Path::new(""))
+    }
+}
+
+/// Load the defaults for the crate currently being compiled
+pub fn load() -> Result<ManifestDefaults, String> {
+    env::var_os("CARGO_MANIFEST_DIR").map_or_else(
+        || Ok(ManifestDefaults::default()),
+        |dir| load_from(Path::new(&dir)),
+    )
+}
+
+/// Load the defaults for the crate in `manifest_dir`
+fn load_from(manifest_dir: &Path) -> Result<ManifestDefaults, String> {
+    let Some(package) = read_manifest(&manifest_dir.join("Cargo.toml"))? else {
+        return Ok(ManifestDefaults::default());
+    };
+
+    // A package that is its own workspace root holds both sections
+    if package.table.contains_key("workspace") {
+        return resolve(&package, Some(&package));
+    }
+    let workspace = match explicit_workspace_root(&package)? {
+        Some(root) => {
+            Some(read_manifest(&root.join("Cargo.toml"))?.ok_or_else(|| {
+                format!(
+                    "`package.workspace` points to {}, which has no Cargo.toml",
+                    root.display()
+                )
+            })?)
+        }
+        None => find_workspace_manifest(&package.path)?,
+    };
+    resolve(&package, workspace.as_ref())
+}
+
+/// Merge `[workspace.metadata.bizerror]` with `[package.metadata.bizerror]`
+fn resolve(
+    package: &Manifest,
+    workspace: Option<&Manifest>,
+) -> Result<ManifestDefaults, String> {
+    let mut defaults = ManifestDefaults::default();
+
+    if let Some(workspace) = workspace {
+        if workspace.path != package.path {
+            defaults.manifests.push(workspace.path.clone());
+        }
+        if let Some(table) = bizerror_table(&workspace.table, "workspace") {
+            apply(&mut defaults, table, "workspace.metadata.bizerror")?;
+        }
+    }
+    defaults.manifests.push(package.path.clone());
+    if let Some(table) = bizerror_table(&package.table, "package") {
+        apply(&mut defaults, table, "package.metadata.bizerror")?;
+    }
+
+    Ok(defaults)
+}
+
+fn bizerror_table<'a>(manifest: &'a Table, section: &str) -> Option<&'a Table> {
+    manifest
+        .get(section)?
+        .get("metadata")?
+        .get("bizerror")?
+        .as_table()
+}
+
+fn apply(
+    defaults: &mut ManifestDefaults,
+    table: &Table,
+    section: &str,
+) -> Result<(), String> {
+    for (key, value) in table {
+        match key.as_str() {
+            "code_type" => {
+                defaults.code_type = Some(expect_str(value, section, key)?);
+            }
+            "auto_start" => {
+                defaults.auto_start = Some(expect_int(value, section, key)?);
+            }
+            "auto_increment" => {
+                defaults.auto_increment =
+                    Some(expect_int(value, section, key)?);
+            }
+            "name_case" => {
+                defaults.name_case = Some(expect_str(value, section, key)?);
+            }
+            "doc_url" => {
+                defaults.doc_url = Some(expect_str(value, section, key)?);
+            }
+            "severity" => {
+                defaults.severity = Some(expect_str(value, section, key)?);
+            }
+            _ => {
+                return Err(format!(
+                    "[{section}]: {}",
+                    validate::unknown_message("key", key, SUPPORTED_KEYS)
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The workspace root set with `package.workspace = "path"`, relative to the
+/// package
+fn explicit_workspace_root(
+    package: &Manifest,
+) -> Result<Option<PathBuf>, String> {
+    let Some(value) = package
+        .table
+        .get("package")
+        .and_then(|package| package.get("workspace"))
+    else {
+        return Ok(None);
+    };
+    let root = expect_str(value, "package", "workspace")?;
+    Ok(Some(package.dir().join(root)))
+}
+
+fn expect_str(
+    value: &Value,
+    section: &str,
+    key: &str,
+) -> Result<String, String> {
+    value
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| format!("[{section}] `{key}` must be a string"))
+}
+
+fn expect_int(value: &Value, section: &str, key: &str) -> Result<i64, String> {
+    value
+        .as_integer()
+        .ok_or_else(|| format!("[{section}] `{key}` must be an integer"))
+}
+
+fn read_manifest(path: &Path) -> Result<Option<Manifest>, String> {
+    let Ok(content) = fs::read_to_string(path) else {
+        return Ok(None);
+    };
+    let table = content
+        .parse::<Table>()
+        .map_err(|e| format!("failed to parse {}: {e}", path.display()))?;
+    Ok(Some(Manifest {
+        path: path.to_path_buf(),
+        table,
+    }))
+}
+
+/// Walk up from the crate manifest to the first manifest with `[workspace]`
+fn find_workspace_manifest(
+    manifest_path: &Path,
+) -> Result<Option<Manifest>, String> {
+    let mut dir: Option<PathBuf> = manifest_path
+        .parent()
+        .and_then(Path::parent)
+        .map(Path::to_path_buf);
+
+    while let Some(current) = dir {
+        let candidate = current.join("Cargo.toml");
+        if let Some(manifest) = read_manifest(&candidate)? &&
+            manifest.table.contains_key("workspace")
+        {
+            return Ok(Some(manifest));
+        }
+        dir = current.parent().map(Path::to_path_buf);
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(path: &str, content: &str) -> Manifest {
+        Manifest {
+            path:  PathBuf::from(path),
+            table: content.parse().expect("valid toml"),
+        }
+    }
+
+    /// A scratch directory holding the given manifests
+    fn scratch(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root = env::temp_dir()
+            .join(format!("bizerror-metadata-{name}-{}", std::process::id()));
+        for (path, content) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().expect("parent"))
+                .expect("create dir");
+            fs::write(path, content).expect("write manifest");
+        }
+        root
+    }
+
+    #[test]
+    fn package_overrides_workspace() {
+        let workspace = manifest(
+            "/ws/Cargo.toml",
+            r#"
+            [workspace.metadata.bizerror]
+            code_type = "u16"
+            auto_start = 1000
+            name_case = "snake_case"
+            "#,
+        );
+        let package = manifest(
+            "/ws/api/Cargo.toml",
+            r#"
+            [package.metadata.bizerror]
+            auto_start = 2000
+            auto_increment = 10
+            doc_url = "https://errors.example.com/{code}"
+            severity = "warning"
+            "#,
+        );
+
+        assert_eq!(
+            resolve(&package, Some(&workspace)),
+            Ok(ManifestDefaults {
+                code_type:      Some("u16".to_string()),
+                auto_start:     Some(2000),
+                auto_increment: Some(10),
+                name_case:      Some("snake_case".to_string()),
+                doc_url:        Some(
+                    "https://errors.example.com/{code}".to_string()
+                ),
+                severity:       Some("warning".to_string()),
+                manifests:      vec![
+                    PathBuf::from("/ws/Cargo.toml"),
+                    PathBuf::from("/ws/api/Cargo.toml"),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn missing_metadata_yields_no_defaults() {
+        let package =
+            manifest("/demo/Cargo.toml", "[package]\nname = \"demo\"");
+        assert_eq!(
+            resolve(&package, None),
+            Ok(ManifestDefaults {
+                manifests: vec![PathBuf::from("/demo/Cargo.toml")],
+                ..ManifestDefaults::default()
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_keys_and_wrong_types() {
+        let package = manifest(
+            "/demo/Cargo.toml",
+            "[package.metadata.bizerror]\ncode_typ = \"u8\"",
+        );
+        let error = resolve(&package, None).expect_err("unknown key");
+        assert!(error.contains("unknown key `code_typ`"));
+        assert!(error.contains("did you mean `code_type`?"));
+
+        let package = manifest(
+            "/demo/Cargo.toml",
+            "[package.metadata.bizerror]\nauto_start = \"1\"",
+        );
+        let error = resolve(&package, None).expect_err("wrong type");
+        assert!(error.contains("`auto_start` must be an integer"));
+    }
+
+    #[test]
+    fn follows_explicit_workspace_path() {
+        // The workspace root is a sibling, so walking up can't find it
+        let root = scratch("explicit", &[
+            (
+                "root/Cargo.toml",
+                "[workspace]\n[workspace.metadata.bizerror]\nauto_start = 100",
+            ),
+            (
+                "member/Cargo.toml",
+                "[package]\nname = \"member\"\nworkspace = \"../root\"",
+            ),
+        ]);
+
+        let defaults = load_from(&root.join("member")).expect("defaults");
+        assert_eq!(defaults.auto_start, Some(100));
+        assert_eq!(defaults.manifests, [
+            root.join("member/../root/Cargo.toml"),
+            root.join("member/Cargo.toml"),
+        ]);
+
+        fs::remove_dir_all(root).expect("cleanup");
+    }
+
+    #[test]
+    fn rejects_dangling_workspace_path() {
+        let root = scratch("dangling", &[(
+            "member/Cargo.toml",
+            "[package]\nname = \"member\"\nworkspace = \"../missing\"",
+        )]);
+
+        let error = load_from(&root.join("member")).expect_err("no workspace");
+        assert!(error.contains("has no Cargo.toml"));
+
+        fs::remove_dir_all(root).expect("cleanup");
+    }
+
+    #[test]
+    fn walks_up_to_the_workspace_root() {
+        let root = scratch("walk", &[
+            (
+                "Cargo.toml",
+                "[workspace]\n[workspace.metadata.bizerror]\nauto_start = 7",
+            ),
+            ("crates/api/Cargo.toml", "[package]\nname = \"api\""),
+        ]);
+
+        let defaults = load_from(&root.join("crates/api")).expect("defaults");
+        assert_eq!(defaults.auto_start, Some(7));
+        assert_eq!(defaults.manifests, [
+            root.join("Cargo.toml"),
+            root.join("crates/api/Cargo.toml"),
+        ]);
+
+        fs::remove_dir_all(root).expect("cleanup");
+    }
+}
@@ -0,0 +1,198 @@
+//! Placement and duplication checks for the derive's helper attributes
+//!
+//! Each helper attribute is only meaningful in one position: `#[bizconfig]`
+//! and `#[bizmap]` on the enum, `#[bizcode]`, `#[bizhttp]`, `#[bizgrpc]` and
+//! `#[bizseverity]` on variants and `#[biz(...)]` on variant fields. Anything
+//! else is reported instead of silently ignored.
+
+use syn::{
+    Attribute,
+    DataEnum,
+    DeriveInput,
+    Error,
+    Ident,
+    Result,
+};
+
+const ENUM_ATTRS: &[&str] = &["bizconfig", "bizmap"];
+const VARIANT_ATTRS: &[&str] =
+    &["bizcode", "bizhttp", "bizgrpc", "bizseverity"];
+const FIELD_ATTRS: &[&str] = &["biz"];
+
+pub fn check_attributes(
+    input: &DeriveInput,
+    data_enum: &DataEnum,
+) -> Result<()> {
+    let mut errors = Errors::default();
+
+    check_position(&mut errors, &input.attrs, ENUM_ATTRS, "the enum");
+    check_unique(&mut errors, &input.attrs, "bizconfig", "the enum");
+
+    for variant in &data_enum.variants {
+        let position = format!("variant `{}`", variant.ident);
+        check_position(&mut errors, &variant.attrs, VARIANT_ATTRS, &position);
+        check_unique(&mut errors, &variant.attrs, "bizcode", &position);
+        check_unique(&mut errors, &variant.attrs, "bizhttp", &position);
+        check_unique(&mut errors, &variant.attrs, "bizgrpc", &position);
+        check_unique(&mut errors, &variant.attrs, "bizseverity", &position);
+
+        for field in &variant.fields {
+            check_position(&mut errors, &field.attrs, FIELD_ATTRS, "a field");
+        }
+    }
+
+    errors.finish()
+}
+
+/// Reject variants whose associated code constants would share a name, e.g.
+/// `HttpError` and `HTTPError` both giving `HTTP_ERROR_CODE`
+pub fn check_code_const_names(names: &[(&Ident, Ident)]) -> Result<()> {
+    let mut errors = Errors::default();
+
+    for (i, (variant, const_name)) in names.iter().enumerate() {
+        let earlier = names[..i]
+            .iter()
+            .find(|(_, earlier_const)| earlier_const == const_name);
+        if let Some((earlier, _)) = earlier {
+            errors.push(Error::new_spanned(
+                variant,
+                format!(
+                    "variants `{earlier}` and `{variant}` both map to the \
+                     code constant `{const_name}`, rename one of them"
+                ),
+            ));
+        }
+    }
+
+    errors.finish()
+}
+
+/// Suggest the closest candidate for a misspelled name
+pub fn did_you_mean<'a>(
+    unknown: &str,
+    candidates: &[&'a str],
+) -> Option<&'a str> {
+    let max_distance = (unknown.len() / 3).max(1);
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(unknown, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Error message for an unknown name, with a suggestion when one is close
+pub fn unknown_message(
+    kind: &str,
+    unknown: &str,
+    candidates: &[&str],
+) -> String {
+    did_you_mean(unknown, candidates).map_or_else(
+        || {
+            format!(
+                "unknown {kind} `{unknown}`, expected one of: {}",
+                candidates.join(", ")
+            )
+        },
+        |suggestion| {
+            format!("unknown {kind} `{unknown}`, did you mean `{suggestion}`?")
+        },
+    )
+}
+
+fn check_position(
+    errors: &mut Errors,
+    attrs: &[Attribute],
+    allowed: &[&str],
+    position: &str,
+) {
+    for attr in attrs {
+        let Some(name) = helper_name(attr) else {
+            continue;
+        };
+        if allowed.contains(&name) {
+            continue;
+        }
+        let expected = if ENUM_ATTRS.contains(&name) {
+            "the enum"
+        } else if VARIANT_ATTRS.contains(&name) {
+            "enum variants"
+        } else {
+            "variant fields"
+        };
+        errors.push(Error::new_spanned(
+            attr,
+            format!(
+                "#[{name}] is not allowed on {position}, it belongs on \
+                 {expected}"
+            ),
+        ));
+    }
+}
+
+fn check_unique(
+    errors: &mut Errors,
+    attrs: &[Attribute],
+    name: &str,
+    position: &str,
+) {
+    let mut matching = attrs.iter().filter(|attr| attr.path().is_ident(name));
+    if matching.next().is_none() {
+        return;
+    }
+    for duplicate in matching {
+        errors.push(Error::new_spanned(
+            duplicate,
+            format!(
+                "duplicate #[{name}] attribute on {position}, only one is \
+                 allowed"
+            ),
+        ));
+    }
+}
+
+fn helper_name(attr: &Attribute) -> Option<&'static str> {
+    ENUM_ATTRS
+        .iter()
+        .chain(VARIANT_ATTRS)
+        .chain(FIELD_ATTRS)
+        .find(|name| attr.path().is_ident(name))
+        .copied()
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(
+                substitution.min(previous[j + 1] + 1).min(current[j] + 1),
+            );
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Accumulates errors so that every problem is reported in one build
+#[derive(Default)]
+struct Errors {
+    error: Option<Error>,
+}
+
+impl Errors {
+    fn push(&mut self, error: Error) {
+        match &mut self.error {
+            Some(existing) => existing.combine(error),
+            None => self.error = Some(error),
+        }
+    }
+
+    fn finish(self) -> Result<()> {
+        self.error.map_or(Ok(()), Err)
+    }
+}
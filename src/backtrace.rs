@@ -0,0 +1,105 @@
+//! Backtrace capture for `ContextualError`, behind the `backtrace` feature
+
+use core::{
+    any::Any,
+    error::Error,
+};
+use std::{
+    backtrace::{
+        Backtrace,
+        BacktraceStatus,
+    },
+    sync::RwLock,
+};
+
+use crate::{
+    BizError,
+    Severity,
+};
+
+/// Decides whether a [`ContextualError`](crate::ContextualError) captures a
+/// backtrace
+///
+/// Return `false` to skip the capture, e.g. for expected errors on hot paths.
+pub type BacktracePolicy = fn(&BacktraceRequest<'_>) -> bool;
+
+static POLICY: RwLock<Option<BacktracePolicy>> = RwLock::new(None);
+
+/// The error a [`BacktracePolicy`] is asked about
+pub struct BacktraceRequest<'a> {
+    error:    &'a dyn Error,
+    name:     &'a str,
+    code:     &'a dyn Any,
+    severity: Severity,
+}
+
+impl BacktraceRequest<'_> {
+    /// Get the error being wrapped
+    pub fn error(&self) -> &dyn Error {
+        self.error
+    }
+
+    /// Get the business error name (variant name for derived errors)
+    pub const fn name(&self) -> &str {
+        self.name
+    }
+
+    /// Get the business error code if its type is `C`
+    pub fn code<C: 'static>(&self) -> Option<&C> {
+        self.code.downcast_ref()
+    }
+
+    /// Get the severity, see [`BizError::severity`]
+    pub const fn severity(&self) -> Severity {
+        self.severity
+    }
+}
+
+/// Install the process-wide backtrace policy, or remove it with `None`
+///
+/// Without a policy every `ContextualError` captures a backtrace. Capturing
+/// still honours `RUST_LIB_BACKTRACE` / `RUST_BACKTRACE`, so the policy can
+/// only narrow what the environment enables.
+///
+/// # Example
+///
+/// ```rust
+/// use bizerror::*;
+///
+/// // Only capture backtraces for system errors (8xxx codes) and errors
+/// // that are at least critical
+/// set_backtrace_policy(Some(|request| {
+///     request.severity() >= Severity::Critical ||
+///         request
+///             .code::<u32>()
+///             .is_some_and(|code| (8000..9000).contains(code))
+/// }));
+/// # set_backtrace_policy(None);
+/// ```
+pub fn set_backtrace_policy(policy: Option<BacktracePolicy>) {
+    *POLICY
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = policy;
+}
+
+/// Capture a backtrace for `error` if the policy and environment allow it
+pub(crate) fn capture<E: BizError>(error: &E) -> Option<Backtrace> {
+    let policy = *POLICY
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if let Some(policy) = policy {
+        let code = error.code();
+        let request = BacktraceRequest {
+            error,
+            name: error.name(),
+            code: &code,
+            severity: error.severity(),
+        };
+        if !policy(&request) {
+            return None;
+        }
+    }
+
+    let backtrace = Backtrace::capture();
+    (backtrace.status() == BacktraceStatus::Captured).then_some(backtrace)
+}
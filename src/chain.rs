@@ -0,0 +1,276 @@
+//! Walking `source()` chains and discovering business errors in them
+
+use alloc::{
+    string::{
+        String,
+        ToString,
+    },
+    vec::{
+        self,
+        Vec,
+    },
+};
+#[cfg(feature = "std")]
+use core::any::TypeId;
+use core::error::Error;
+#[cfg(feature = "std")]
+use std::sync::{
+    PoisonError,
+    RwLock,
+};
+
+#[cfg(feature = "std")]
+use crate::BizError;
+use crate::{
+    BizCode,
+    DynBizError,
+    REDACTED,
+};
+
+/// One error in a chain walked by [`ContextualError::chain`]
+///
+/// [`ContextualError::chain`]: crate::ContextualError::chain
+#[derive(Clone, Copy)]
+pub struct ChainLink<'a> {
+    error: &'a (dyn Error + 'static),
+    biz:   Option<&'a dyn DynBizError>,
+    depth: usize,
+}
+
+impl<'a> ChainLink<'a> {
+    /// Get the error at this link
+    pub const fn error(&self) -> &'a (dyn Error + 'static) {
+        self.error
+    }
+
+    /// Get the error message (its `Display` output)
+    ///
+    /// The message of a sensitive business error is `<redacted>`, see
+    /// [`BizError::is_sensitive`](crate::BizError::is_sensitive).
+    pub fn message(&self) -> String {
+        if self.biz.is_some_and(DynBizError::biz_is_sensitive) {
+            return REDACTED.to_string();
+        }
+        self.error.to_string()
+    }
+
+    /// Get the position in the chain, `0` being the outermost error
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Get the business error view, if the error is a recognized `BizError`
+    pub const fn biz_error(&self) -> Option<&'a dyn DynBizError> {
+        self.biz
+    }
+
+    /// Get the business code, if the error is a recognized `BizError`
+    pub fn code(&self) -> Option<BizCode> {
+        self.biz.map(DynBizError::biz_code)
+    }
+
+    /// Get the business error name, if the error is a recognized `BizError`
+    pub fn name(&self) -> Option<&'a str> {
+        self.biz.map(DynBizError::biz_name)
+    }
+
+    /// Get the error at this link if it is of type `T`
+    pub fn downcast_ref<T: Error + 'static>(&self) -> Option<&'a T> {
+        self.error.downcast_ref()
+    }
+}
+
+impl core::fmt::Debug for ChainLink<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ChainLink")
+            .field("depth", &self.depth)
+            .field("code", &self.code())
+            .field("name", &self.name())
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+/// Iterator over the links of an error chain, outermost first
+///
+/// Returned by [`ContextualError::chain`]. Use `rev()` to walk from the root
+/// cause outwards, or [`root`](Self::root) to jump to the root cause.
+///
+/// [`ContextualError::chain`]: crate::ContextualError::chain
+#[derive(Debug, Clone)]
+pub struct Chain<'a> {
+    links: vec::IntoIter<ChainLink<'a>>,
+}
+
+impl<'a> Chain<'a> {
+    /// Walk `error` and its sources, using `probe` to recognize business
+    /// errors
+    pub(crate) fn new(
+        error: &'a (dyn Error + 'static),
+        probe: impl Fn(&'a (dyn Error + 'static)) -> Option<&'a dyn DynBizError>,
+    ) -> Self {
+        let mut links = Vec::new();
+        let mut current = Some(error);
+        while let Some(error) = current {
+            links.push(ChainLink {
+                error,
+                biz: probe(error),
+                depth: links.len(),
+            });
+            current = error.source();
+        }
+        Self {
+            links: links.into_iter(),
+        }
+    }
+
+    /// Get the root cause, the innermost link of the chain
+    pub fn root(self) -> Option<ChainLink<'a>> {
+        self.links.last()
+    }
+}
+
+impl<'a> Iterator for Chain<'a> {
+    type Item = ChainLink<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.links.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.links.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Chain<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.links.next_back()
+    }
+}
+
+impl ExactSizeIterator for Chain<'_> {}
+
+#[cfg(feature = "std")]
+type Probe =
+    for<'a> fn(&'a (dyn Error + 'static)) -> Option<&'a dyn DynBizError>;
+
+#[cfg(feature = "std")]
+static REGISTRY: RwLock<Vec<(TypeId, Probe)>> = RwLock::new(Vec::new());
+
+/// Register `E` so the chain walkers in [`ErrorChainExt`] can recognize it
+///
+/// A `&dyn Error` can only be downcast to a known concrete type, so every
+/// `BizError` type that should be discovered in foreign chains has to be
+/// registered once, typically at startup. Registering a type twice is a
+/// no-op.
+///
+/// `ContextualError<E>` does not need registering: its source is the `E` it
+/// wraps, which is discovered on its own.
+#[cfg(feature = "std")]
+pub fn register_biz_error<E: BizError>() {
+    fn probe<'a, E: BizError>(
+        error: &'a (dyn Error + 'static),
+    ) -> Option<&'a dyn DynBizError> {
+        error.downcast_ref::<E>().map(|e| e as &dyn DynBizError)
+    }
+
+    let id = TypeId::of::<E>();
+    let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+    if !registry.iter().any(|(registered, _)| *registered == id) {
+        registry.push((id, probe::<E>));
+    }
+}
+
+/// View `error` as a business error if its type is registered
+#[cfg(feature = "std")]
+pub fn as_biz_error<'a>(
+    error: &'a (dyn Error + 'static),
+) -> Option<&'a dyn DynBizError> {
+    let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+    registry.iter().find_map(|(_, probe)| probe(error))
+}
+
+/// Chain walkers that discover registered business errors of any type
+///
+/// # Example
+///
+/// ```rust
+/// use bizerror::*;
+///
+/// #[derive(BizError, thiserror::Error)]
+/// pub enum UserServiceError {
+///     #[bizcode(2001)]
+///     #[error("User not found")]
+///     NotFound,
+/// }
+///
+/// #[derive(BizError, thiserror::Error)]
+/// pub enum AppError {
+///     #[bizcode(5001)]
+///     #[error("User service failed")]
+///     UserService(#[from] UserServiceError),
+/// }
+///
+/// register_biz_error::<AppError>();
+/// register_biz_error::<UserServiceError>();
+///
+/// let error = AppError::from(UserServiceError::NotFound);
+/// let found: Vec<_> = error
+///     .biz_errors()
+///     .into_iter()
+///     .map(|(_, code, name)| (code, name))
+///     .collect();
+/// assert_eq!(found, [
+///     (BizCode::Int(5001), "UserService"),
+///     (BizCode::Int(2001), "NotFound")
+/// ]);
+/// assert_eq!(error.innermost_biz_code(), Some(BizCode::Int(2001)));
+/// ```
+#[cfg(feature = "std")]
+pub trait ErrorChainExt {
+    /// List `(type_name, code, name)` of every registered business error in
+    /// the chain, from the outermost error to the root cause
+    fn biz_errors(&self) -> Vec<(&'static str, BizCode, &str)>;
+
+    /// Get the code of the registered business error closest to the root
+    /// cause
+    fn innermost_biz_code(&self) -> Option<BizCode>;
+}
+
+#[cfg(feature = "std")]
+impl ErrorChainExt for dyn Error + 'static {
+    fn biz_errors(&self) -> Vec<(&'static str, BizCode, &str)> {
+        Chain::new(self, as_biz_error)
+            .filter_map(|link| link.biz)
+            .map(|biz| (biz.type_name(), biz.biz_code(), biz.biz_name()))
+            .collect()
+    }
+
+    fn innermost_biz_code(&self) -> Option<BizCode> {
+        Chain::new(self, as_biz_error)
+            .rev()
+            .find_map(|link| link.code())
+    }
+}
+
+#[cfg(feature = "std")]
+impl ErrorChainExt for dyn Error + Send + Sync + 'static {
+    fn biz_errors(&self) -> Vec<(&'static str, BizCode, &str)> {
+        (self as &(dyn Error + 'static)).biz_errors()
+    }
+
+    fn innermost_biz_code(&self) -> Option<BizCode> {
+        (self as &(dyn Error + 'static)).innermost_biz_code()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: Error + 'static> ErrorChainExt for E {
+    fn biz_errors(&self) -> Vec<(&'static str, BizCode, &str)> {
+        (self as &(dyn Error + 'static)).biz_errors()
+    }
+
+    fn innermost_biz_code(&self) -> Option<BizCode> {
+        (self as &(dyn Error + 'static)).innermost_biz_code()
+    }
+}
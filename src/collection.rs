@@ -0,0 +1,408 @@
+//! Aggregation of multiple business errors
+
+use alloc::{
+    format,
+    string::String,
+    vec::Vec,
+};
+use core::error::Error;
+
+use crate::{
+    BizError,
+    ContextualError,
+};
+
+/// Business errors collection for aggregating multiple errors
+///
+/// This type is useful for scenarios where you need to collect all errors
+/// instead of failing on the first one, such as form validation or batch
+/// processing.
+///
+/// ## Example
+///
+/// ```rust
+/// use bizerror::*;
+///
+/// #[derive(BizError, thiserror::Error)]
+/// pub enum ValidationError {
+///     #[bizcode(4001)]
+///     #[error("Invalid email: {email}")]
+///     InvalidEmail { email: String },
+///
+///     #[bizcode(4002)]
+///     #[error("Password too short")]
+///     PasswordTooShort,
+/// }
+///
+/// fn validate_user(
+///     email: &str,
+///     password: &str,
+/// ) -> Result<(), BizErrors<ValidationError>> {
+///     let mut errors = BizErrors::new();
+///
+///     if !email.contains('@') {
+///         errors.push_simple(ValidationError::InvalidEmail {
+///             email: email.to_string(),
+///         });
+///     }
+///
+///     if password.len() < 8 {
+///         errors.push_simple(ValidationError::PasswordTooShort);
+///     }
+///
+///     if errors.is_empty() {
+///         Ok(())
+///     } else {
+///         Err(errors)
+///     }
+/// }
+/// ```
+pub struct BizErrors<E: BizError> {
+    errors: Vec<ContextualError<E>>,
+}
+
+impl<E: BizError> BizErrors<E> {
+    /// Create a new empty error collection
+    pub const fn new() -> Self {
+        Self { errors: Vec::new() }
+    }
+
+    /// Create a new error collection with the given capacity
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            errors: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Add a contextual error to the collection
+    pub fn push(&mut self, error: ContextualError<E>) {
+        self.errors.push(error);
+    }
+
+    /// Add a simple business error to the collection
+    ///
+    /// The error will be wrapped in a `ContextualError` with minimal context.
+    #[track_caller]
+    pub fn push_simple(&mut self, error: E) {
+        self.errors
+            .push(ContextualError::with_static_context(error, ""));
+    }
+
+    /// Add a business error with context to the collection
+    #[track_caller]
+    pub fn push_with_context(&mut self, error: E, context: impl Into<String>) {
+        self.errors.push(ContextualError::new(error, context));
+    }
+
+    /// Get the number of errors in the collection
+    pub const fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Check if the error collection is empty
+    pub const fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Get an iterator over the errors
+    pub fn iter(&self) -> impl Iterator<Item = &ContextualError<E>> {
+        self.errors.iter()
+    }
+
+    /// Get a reference to the errors vector
+    pub fn as_slice(&self) -> &[ContextualError<E>] {
+        &self.errors
+    }
+
+    /// Convert into the underlying errors vector
+    pub fn into_vec(self) -> Vec<ContextualError<E>> {
+        self.errors
+    }
+
+    /// Get the first error in the collection
+    pub fn first(&self) -> Option<&ContextualError<E>> {
+        self.errors.first()
+    }
+
+    /// Get the last error in the collection
+    pub fn last(&self) -> Option<&ContextualError<E>> {
+        self.errors.last()
+    }
+
+    /// Collect successful results and errors from an iterator
+    ///
+    /// Returns a tuple containing all successful values and optionally
+    /// the collected errors (if any occurred).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bizerror::*;
+    ///
+    /// #[derive(BizError, thiserror::Error)]
+    /// pub enum ProcessError {
+    ///     #[bizcode(5001)]
+    ///     #[error("Invalid value: {value}")]
+    ///     InvalidValue { value: i32 },
+    /// }
+    ///
+    /// let results: Vec<Result<i32, ContextualError<ProcessError>>> = vec![
+    ///     Ok(1),
+    ///     Ok(2),
+    ///     Err(ProcessError::InvalidValue { value: 3 }
+    ///         .with_context("Processing item 3")),
+    ///     Ok(4),
+    ///     Err(ProcessError::InvalidValue { value: 5 }
+    ///         .with_context("Processing item 5")),
+    /// ];
+    ///
+    /// let (successes, errors) = BizErrors::collect_from(results.into_iter());
+    /// assert_eq!(successes, vec![1, 2, 4]);
+    /// assert!(errors.is_some());
+    /// assert_eq!(errors.unwrap().len(), 2);
+    /// ```
+    pub fn collect_from<T, I>(iter: I) -> (Vec<T>, Option<Self>)
+    where
+        I: Iterator<Item = Result<T, ContextualError<E>>>,
+    {
+        let mut successes = Vec::new();
+        let mut errors = Self::new();
+
+        for result in iter {
+            match result {
+                Ok(value) => successes.push(value),
+                Err(error) => errors.push(error),
+            }
+        }
+
+        let errors = if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        };
+
+        (successes, errors)
+    }
+
+    /// Collect all errors from an iterator of Results
+    ///
+    /// Returns `None` if no errors occurred, or `Some(BizErrors)` with all
+    /// errors.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bizerror::*;
+    ///
+    /// #[derive(BizError, thiserror::Error)]
+    /// pub enum ValidationError {
+    ///     #[bizcode(4001)]
+    ///     #[error("Invalid field")]
+    ///     InvalidField,
+    /// }
+    ///
+    /// let results: Vec<Result<(), ContextualError<ValidationError>>> = vec![
+    ///     Ok(()),
+    ///     Err(ValidationError::InvalidField.with_context("Field 1")),
+    ///     Err(ValidationError::InvalidField.with_context("Field 2")),
+    /// ];
+    ///
+    /// let errors = BizErrors::collect_errors(results.into_iter());
+    /// assert!(errors.is_some());
+    /// assert_eq!(errors.unwrap().len(), 2);
+    /// ```
+    pub fn collect_errors<T, I>(iter: I) -> Option<Self>
+    where
+        I: Iterator<Item = Result<T, ContextualError<E>>>,
+    {
+        let mut errors = Self::new();
+
+        for result in iter {
+            if let Err(error) = result {
+                errors.push(error);
+            }
+        }
+
+        if errors.is_empty() {
+            None
+        } else {
+            Some(errors)
+        }
+    }
+
+    /// Check if any error in the collection has the specified code
+    pub fn contains_code<C>(&self, code: C) -> bool
+    where
+        C: PartialEq<E::CodeType> + Copy,
+    {
+        self.errors.iter().any(|error| code == error.code())
+    }
+
+    /// Get all unique error codes in the collection
+    pub fn error_codes(&self) -> Vec<E::CodeType> {
+        let mut codes: Vec<E::CodeType> =
+            self.errors.iter().map(BizError::code).collect();
+        codes.sort_by(|a, b| format!("{a:?}").cmp(&format!("{b:?}")));
+        codes.dedup();
+        codes
+    }
+
+    /// Filter errors by a predicate
+    ///
+    /// Returns an iterator over the errors that satisfy the given predicate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bizerror::*;
+    ///
+    /// #[derive(BizError, thiserror::Error)]
+    /// pub enum MyError {
+    ///     #[bizcode(4001)]
+    ///     #[error("Validation error")]
+    ///     ValidationError,
+    ///
+    ///     #[bizcode(8001)]
+    ///     #[error("System error")]
+    ///     SystemError,
+    /// }
+    ///
+    /// let mut errors = BizErrors::new();
+    /// errors.push_simple(MyError::ValidationError);
+    /// errors.push_simple(MyError::SystemError);
+    ///
+    /// // Filter only validation errors (4xxx codes)
+    /// let validation_errors: Vec<_> = errors
+    ///     .filter(|e| e.code() >= 4000 && e.code() < 5000)
+    ///     .collect();
+    /// assert_eq!(validation_errors.len(), 1);
+    /// ```
+    pub fn filter<F>(
+        &self,
+        predicate: F,
+    ) -> impl Iterator<Item = &ContextualError<E>>
+    where
+        F: Fn(&ContextualError<E>) -> bool,
+    {
+        self.errors.iter().filter(move |e| predicate(*e))
+    }
+}
+
+impl<E: BizError> Default for BizErrors<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: BizError> IntoIterator for BizErrors<E> {
+    type Item = ContextualError<E>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<E: BizError> core::fmt::Debug for BizErrors<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.errors.is_empty() {
+            f.debug_struct("BizErrors").field("count", &0).finish()
+        } else if self.errors.len() == 1 {
+            f.debug_struct("BizErrors")
+                .field("count", &1)
+                .field("error", &self.errors[0])
+                .finish()
+        } else {
+            let mut debug_struct = f.debug_struct("BizErrors");
+            debug_struct.field("count", &self.errors.len());
+
+            let codes: Vec<_> =
+                self.errors.iter().map(BizError::code).collect();
+            debug_struct.field("codes", &codes);
+
+            // Show first few errors for detailed view
+            if self.errors.len() <= 3 {
+                debug_struct.field("errors", &self.errors);
+            } else {
+                debug_struct.field("first_3_errors", &&self.errors[0..3]);
+                debug_struct.field(
+                    "note",
+                    &format!("... and {} more", self.errors.len() - 3),
+                );
+            }
+
+            debug_struct.finish()
+        }
+    }
+}
+
+impl<E: BizError> core::fmt::Display for BizErrors<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.errors.is_empty() {
+            write!(f, "No errors")
+        } else if self.errors.len() == 1 {
+            write!(f, "{}", self.errors[0])
+        } else {
+            writeln!(
+                f,
+                "Multiple errors occurred ({} total):",
+                self.errors.len()
+            )?;
+            for (i, error) in self.errors.iter().enumerate() {
+                writeln!(f, "  {}. {}", i + 1, error)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+impl<E: BizError> Error for BizErrors<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        // Return the first error as the source
+        self.errors.first().map(|e| e as &dyn Error)
+    }
+}
+
+impl<E: BizError> BizError for BizErrors<E> {
+    type CodeType = E::CodeType;
+
+    fn code(&self) -> Self::CodeType {
+        // Return the code of the first error
+        self.errors
+            .first()
+            .map_or_else(|| panic!("BizErrors is empty"), BizError::code)
+    }
+
+    fn name(&self) -> &'static str {
+        "BizErrors"
+    }
+}
+
+impl<'a, E: BizError> IntoIterator for &'a BizErrors<E> {
+    type Item = &'a ContextualError<E>;
+    type IntoIter = core::slice::Iter<'a, ContextualError<E>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+// Allow collecting Results into BizErrors
+impl<E: BizError> FromIterator<ContextualError<E>> for BizErrors<E> {
+    fn from_iter<T: IntoIterator<Item = ContextualError<E>>>(iter: T) -> Self {
+        Self {
+            errors: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<E: BizError> FromIterator<E> for BizErrors<E> {
+    #[track_caller]
+    fn from_iter<T: IntoIterator<Item = E>>(iter: T) -> Self {
+        Self {
+            errors: iter
+                .into_iter()
+                .map(|e| ContextualError::with_static_context(e, ""))
+                .collect(),
+        }
+    }
+}
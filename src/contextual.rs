@@ -0,0 +1,1071 @@
+//! Context wrapper and extension traits that attach context to errors
+
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{
+        String,
+        ToString,
+    },
+    vec::Vec,
+};
+use core::{
+    error::Error,
+    panic::Location,
+};
+
+use crate::{
+    BizError,
+    BizFieldValue,
+    Chain,
+    DynBizError,
+    GrpcCode,
+    REDACTED,
+    ReportEntry,
+    Severity,
+    StaticContextualError,
+};
+
+/// Contextual error wrapper (only used when detailed context is needed)
+///
+/// This wrapper allows you to add context information and automatic location
+/// tracking to any `BizError` without changing the original error type.
+///
+/// ## When to Use
+///
+/// Use `ContextualError` when you need:
+/// - Detailed debugging information
+/// - Location tracking for where the error occurred
+/// - Additional context about the operation that failed
+/// - Multiple layers of context in error chains
+///
+/// ## Example
+///
+/// ```rust
+/// use bizerror::*;
+///
+/// #[derive(BizError, thiserror::Error)]
+/// pub enum ServiceError {
+///     #[bizcode(8001)]
+///     #[error("Database connection failed")]
+///     DatabaseError(#[from] std::io::Error),
+/// }
+///
+/// fn load_config() -> Result<String, ContextualError<ServiceError>> {
+///     std::fs::read_to_string("config.json")
+///         .with_context("Loading application configuration")
+/// }
+/// ```
+pub struct ContextualError<E: BizError> {
+    error:     E,
+    frame:     ContextFrame,
+    // Frames added by `add_context`; a single-frame error never allocates
+    // here
+    outer:     Option<alloc::boxed::Box<OuterFrames>>,
+    // Where the outermost frame was added
+    location:  &'static Location<'static>,
+    #[cfg(feature = "backtrace")]
+    // Boxed to keep `Result<_, ContextualError<_>>` small
+    backtrace: Option<alloc::boxed::Box<std::backtrace::Backtrace>>,
+}
+
+#[derive(Default)]
+struct OuterFrames {
+    // Innermost first
+    frames: Vec<ContextFrame>,
+    // The messages of all frames, including the first, joined by " -> "
+    joined: String,
+}
+
+/// One layer of context attached to a [`ContextualError`]
+///
+/// Each call to [`ContextualError::new`] or [`ContextualError::add_context`]
+/// records a frame with its message and the call site that added it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContextFrame {
+    message:  Cow<'static, str>, // Avoids allocation for static strings
+    location: &'static Location<'static>,
+}
+
+impl ContextFrame {
+    /// Get the context message of this frame
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Get the location where this frame was added
+    pub const fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+}
+
+impl core::fmt::Display for ContextFrame {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}, at {}", self.message, self.location)
+        } else {
+            f.write_str(&self.message)
+        }
+    }
+}
+
+impl<E: BizError> ContextualError<E> {
+    /// Create a new contextual error with automatic location tracking
+    ///
+    /// The location is automatically captured using `#[track_caller]`,
+    /// providing precise information about where the error context was added.
+    #[track_caller]
+    pub fn new(error: E, context: impl Into<String>) -> Self {
+        Self::with_frame(error, Cow::Owned(context.into()), Location::caller())
+    }
+
+    /// Create a new contextual error from a static context string
+    ///
+    /// Unlike [`new`](Self::new), the context is borrowed rather than copied
+    /// into a `String`, so creating the error does not allocate.
+    #[track_caller]
+    // Not `const` with the `backtrace` feature
+    #[cfg_attr(not(feature = "backtrace"), allow(clippy::missing_const_for_fn))]
+    pub fn with_static_context(error: E, context: &'static str) -> Self {
+        Self::with_frame(error, Cow::Borrowed(context), Location::caller())
+    }
+
+    #[cfg_attr(not(feature = "backtrace"), allow(clippy::missing_const_for_fn))]
+    fn with_frame(
+        error: E,
+        message: Cow<'static, str>,
+        location: &'static Location<'static>,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "backtrace")]
+            backtrace: crate::backtrace::capture(&error).map(Into::into),
+            error,
+            frame: ContextFrame { message, location },
+            outer: None,
+            location,
+        }
+    }
+
+    /// Get the original error
+    ///
+    /// This provides access to the underlying `BizError` instance.
+    pub const fn inner(&self) -> &E {
+        &self.error
+    }
+
+    /// Get the backtrace captured when this error was created
+    ///
+    /// Returns `None` if backtraces are disabled through
+    /// `RUST_LIB_BACKTRACE` / `RUST_BACKTRACE`, or if the
+    /// [`BacktracePolicy`](crate::BacktracePolicy) skipped this error.
+    #[cfg(feature = "backtrace")]
+    pub fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+        self.backtrace.as_deref()
+    }
+
+    /// Get the context
+    ///
+    /// Returns the contextual information that was added to this error, with
+    /// the messages of all frames joined by `" -> "`, innermost first.
+    pub fn context(&self) -> &str {
+        self.outer
+            .as_ref()
+            .map_or_else(|| self.frame.message(), |outer| &outer.joined)
+    }
+
+    /// Get the context frames, innermost first
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bizerror::*;
+    ///
+    /// #[derive(BizError, thiserror::Error)]
+    /// pub enum MyError {
+    ///     #[bizcode(8001)]
+    ///     #[error("IO error")]
+    ///     IoError,
+    /// }
+    ///
+    /// let error = MyError::IoError
+    ///     .with_context("Loading file")
+    ///     .add_context("During startup");
+    /// let messages: Vec<_> = error.frames().map(ContextFrame::message).collect();
+    /// assert_eq!(messages, ["Loading file", "During startup"]);
+    /// ```
+    pub fn frames(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = &ContextFrame> + Clone {
+        let outer = self.outer.as_ref().map_or(&[][..], |outer| &outer.frames);
+        core::iter::once(&self.frame).chain(outer)
+    }
+
+    /// Get the location
+    ///
+    /// Returns the location where the outermost context was added to this
+    /// error.
+    pub const fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    fn push_frame(
+        &mut self,
+        message: Cow<'static, str>,
+        location: &'static Location<'static>,
+    ) {
+        let joined = format!("{} -> {message}", self.context());
+        let outer = self.outer.get_or_insert_with(Default::default);
+        outer.joined = joined;
+        outer.frames.push(ContextFrame { message, location });
+        self.location = location;
+    }
+
+    /// Add additional context to the existing context
+    ///
+    /// This method pushes a new [`ContextFrame`] recording the message and the
+    /// caller's location; earlier frames and their locations are kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bizerror::*;
+    ///
+    /// #[derive(BizError, thiserror::Error)]
+    /// pub enum MyError {
+    ///     #[bizcode(8001)]
+    ///     #[error("IO error")]
+    ///     IoError,
+    /// }
+    ///
+    /// let error = MyError::IoError;
+    /// let contextual = error.with_context("Loading file");
+    /// let layered = contextual.add_context("During startup");
+    /// assert_eq!(layered.context(), "Loading file -> During startup");
+    /// ```
+    #[track_caller]
+    #[must_use]
+    pub fn add_context(mut self, additional: impl Into<String>) -> Self {
+        self.push_frame(Cow::Owned(additional.into()), Location::caller());
+        self
+    }
+
+    /// Add a static context string to the existing context
+    ///
+    /// The allocation-free counterpart of
+    /// [`add_context`](Self::add_context) for `&'static str` messages.
+    #[track_caller]
+    #[must_use]
+    pub fn add_static_context(mut self, additional: &'static str) -> Self {
+        self.push_frame(Cow::Borrowed(additional), Location::caller());
+        self
+    }
+
+    /// Unwrap the contextual error, returning the inner error
+    ///
+    /// This method consumes the `ContextualError` and returns the underlying
+    /// business error, discarding the context information.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bizerror::*;
+    ///
+    /// #[derive(BizError, thiserror::Error)]
+    /// pub enum MyError {
+    ///     #[bizcode(8001)]
+    ///     #[error("IO error")]
+    ///     IoError,
+    /// }
+    ///
+    /// let error = MyError::IoError;
+    /// let contextual = error.with_context("Some context");
+    /// let original = contextual.into_inner();
+    /// // original is now MyError::IoError again
+    /// ```
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+
+    /// Replace the inner error, keeping every context frame and location
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bizerror::*;
+    ///
+    /// #[derive(BizError, thiserror::Error)]
+    /// pub enum RepoError {
+    ///     #[bizcode(7001)]
+    ///     #[error("Row not found")]
+    ///     RowNotFound,
+    /// }
+    ///
+    /// #[derive(BizError, thiserror::Error)]
+    /// pub enum ServiceError {
+    ///     #[bizcode(4004)]
+    ///     #[error("Order not found")]
+    ///     OrderNotFound,
+    /// }
+    ///
+    /// let repo = RepoError::RowNotFound.with_context("Loading order 7");
+    /// let service = repo.map_inner(|_| ServiceError::OrderNotFound);
+    /// assert_eq!(service.code(), 4004);
+    /// assert_eq!(service.context(), "Loading order 7");
+    /// ```
+    pub fn map_inner<B, F>(self, f: F) -> ContextualError<B>
+    where
+        B: BizError,
+        F: FnOnce(E) -> B,
+    {
+        ContextualError {
+            error: f(self.error),
+            frame: self.frame,
+            outer: self.outer,
+            location: self.location,
+            #[cfg(feature = "backtrace")]
+            backtrace: self.backtrace,
+        }
+    }
+
+    /// Convert the inner error with `From`, keeping every context frame and
+    /// location
+    ///
+    /// Shorthand for `self.map_inner(B::from)`.
+    pub fn convert<B>(self) -> ContextualError<B>
+    where
+        B: BizError + From<E>,
+    {
+        self.map_inner(B::from)
+    }
+
+    /// Find the first error in the chain of a specific type
+    ///
+    /// This method traverses the error chain and returns the first error
+    /// of the specified type. Useful for extracting specific error types
+    /// from a complex error chain.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io;
+    ///
+    /// use bizerror::*;
+    ///
+    /// #[derive(BizError, thiserror::Error)]
+    /// pub enum MyError {
+    ///     #[bizcode(8001)]
+    ///     #[error("IO error: {0}")]
+    ///     IoError(#[from] io::Error),
+    /// }
+    ///
+    /// let io_error = io::Error::new(io::ErrorKind::NotFound, "file not found");
+    /// let my_error = MyError::IoError(io_error);
+    /// let contextual = my_error.with_context("Loading config");
+    ///
+    /// // Find the original io::Error in the chain
+    /// let found_io_error = contextual.find_root::<io::Error>();
+    /// assert!(found_io_error.is_some());
+    /// ```
+    pub fn find_root<T>(&self) -> Option<&T>
+    where
+        T: Error + 'static,
+    {
+        let mut current: &dyn Error = self;
+        while let Some(source) = current.source() {
+            if let Some(target) = source.downcast_ref::<T>() {
+                return Some(target);
+            }
+            current = source;
+        }
+        None
+    }
+
+    /// Count the depth of the error chain
+    ///
+    /// Returns the number of errors in the chain, including this error.
+    /// Useful for understanding the complexity of error propagation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bizerror::*;
+    /// use std::io;
+    ///
+    /// #[derive(BizError, thiserror::Error)]
+    /// pub enum MyError {
+    ///     #[bizcode(8001)]
+    ///     #[error("IO error: {0}")]
+    ///     IoError(#[from] io::Error),
+    /// }
+    ///
+    /// let io_error = io::Error::new(io::ErrorKind::NotFound, "file not found");
+    /// let my_error = MyError::IoError(io_error);
+    /// let contextual = my_error.with_context("Loading config");
+    ///
+    /// assert_eq!(contextual.chain_depth(), 3); // ContextualError -> MyError -> io::Error
+    /// ```
+    pub fn chain_depth(&self) -> usize {
+        self.chain().len()
+    }
+
+    /// Get the root cause message of the error chain
+    ///
+    /// Returns the deepest error message in the chain, which is typically the
+    /// original cause of the error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io;
+    ///
+    /// use bizerror::*;
+    ///
+    /// #[derive(BizError, thiserror::Error)]
+    /// pub enum MyError {
+    ///     #[bizcode(8001)]
+    ///     #[error("IO error: {0}")]
+    ///     IoError(#[from] io::Error),
+    /// }
+    ///
+    /// let io_error = io::Error::new(io::ErrorKind::NotFound, "file not found");
+    /// let my_error = MyError::IoError(io_error);
+    /// let contextual = my_error.with_context("Loading config");
+    ///
+    /// let root_cause = contextual.root_cause_message();
+    /// assert_eq!(root_cause, "file not found");
+    /// ```
+    pub fn root_cause_message(&self) -> String {
+        self.chain()
+            .root()
+            .map_or_else(|| self.to_string(), |link| link.message())
+    }
+
+    /// Collect all error messages in the chain
+    ///
+    /// Returns a vector of all error messages in the chain, from the current
+    /// error to the root cause. Useful for comprehensive error reporting.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io;
+    ///
+    /// use bizerror::*;
+    ///
+    /// #[derive(BizError, thiserror::Error)]
+    /// pub enum MyError {
+    ///     #[bizcode(8001)]
+    ///     #[error("IO error: {0}")]
+    ///     IoError(#[from] io::Error),
+    /// }
+    ///
+    /// let io_error = io::Error::new(io::ErrorKind::NotFound, "file not found");
+    /// let my_error = MyError::IoError(io_error);
+    /// let contextual = my_error.with_context("Loading config");
+    ///
+    /// let chain = contextual.error_chain_messages();
+    /// assert_eq!(chain.len(), 3);
+    /// ```
+    pub fn error_chain_messages(&self) -> Vec<String> {
+        self.chain().map(|link| link.message()).collect()
+    }
+
+    /// Iterate over the error chain, from this error to the root cause
+    ///
+    /// Each [`ChainLink`](crate::ChainLink) exposes the `&dyn Error`, its
+    /// message and, for business errors, the code and name. `E` and
+    /// `ContextualError<E>` are always recognized; other `BizError` types
+    /// are recognized once registered with
+    /// [`register_biz_error`](crate::register_biz_error).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io;
+    ///
+    /// use bizerror::*;
+    ///
+    /// #[derive(BizError, thiserror::Error)]
+    /// pub enum MyError {
+    ///     #[bizcode(8001)]
+    ///     #[error("IO error: {0}")]
+    ///     IoError(#[from] io::Error),
+    /// }
+    ///
+    /// let io_error = io::Error::new(io::ErrorKind::NotFound, "file not found");
+    /// let contextual = MyError::IoError(io_error).with_context("Loading config");
+    ///
+    /// let codes: Vec<_> = contextual.chain().filter_map(|l| l.code()).collect();
+    /// assert_eq!(codes, [BizCode::Int(8001), BizCode::Int(8001)]);
+    ///
+    /// let root = contextual.chain().root().unwrap();
+    /// assert_eq!(root.message(), "file not found");
+    /// assert!(root.downcast_ref::<io::Error>().is_some());
+    /// assert_eq!(
+    ///     contextual.chain().rev().nth(1).unwrap().name(),
+    ///     Some("IoError")
+    /// );
+    /// ```
+    pub fn chain(&self) -> Chain<'_> {
+        Chain::new(self, |error| {
+            if let Some(contextual) = error.downcast_ref::<Self>() {
+                return Some(contextual as &dyn DynBizError);
+            }
+            if let Some(inner) = error.downcast_ref::<E>() {
+                return Some(inner as &dyn DynBizError);
+            }
+            #[cfg(feature = "std")]
+            if let Some(biz) = crate::as_biz_error(error) {
+                return Some(biz);
+            }
+            None
+        })
+    }
+
+    /// Check if the error chain contains a specific error type
+    ///
+    /// Returns true if any error in the chain is of the specified type.
+    /// Useful for conditional error handling.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io;
+    ///
+    /// use bizerror::*;
+    ///
+    /// #[derive(BizError, thiserror::Error)]
+    /// pub enum MyError {
+    ///     #[bizcode(8001)]
+    ///     #[error("IO error: {0}")]
+    ///     IoError(#[from] io::Error),
+    /// }
+    ///
+    /// let io_error = io::Error::new(io::ErrorKind::NotFound, "file not found");
+    /// let my_error = MyError::IoError(io_error);
+    /// let contextual = my_error.with_context("Loading config");
+    ///
+    /// assert!(contextual.contains_error::<io::Error>());
+    /// assert!(!contextual.contains_error::<core::fmt::Error>());
+    /// ```
+    pub fn contains_error<T>(&self) -> bool
+    where
+        T: Error + 'static,
+    {
+        self.find_root::<T>().is_some()
+    }
+
+    /// Check if the error chain contains a specific business error code
+    ///
+    /// Returns true if any `BizError` in the chain has the specified code.
+    /// Useful for conditional error handling based on business error codes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bizerror::*;
+    ///
+    /// #[derive(BizError, thiserror::Error)]
+    /// pub enum MyError {
+    ///     #[bizcode(8001)]
+    ///     #[error("IO error")]
+    ///     IoError,
+    ///
+    ///     #[bizcode(8002)]
+    ///     #[error("Network error")]
+    ///     NetworkError,
+    /// }
+    ///
+    /// let error = MyError::IoError;
+    /// let contextual = error.with_context("Operation failed");
+    ///
+    /// assert!(contextual.chain_contains_code(8001));
+    /// assert!(!contextual.chain_contains_code(8002));
+    /// ```
+    pub fn chain_contains_code<C>(&self, code: C) -> bool
+    where
+        C: PartialEq<E::CodeType> + Copy,
+    {
+        let mut current: &dyn Error = self;
+        loop {
+            if let Some(biz_error) = current.downcast_ref::<E>() &&
+                code == biz_error.code()
+            {
+                return true;
+            }
+            if let Some(contextual) = current.downcast_ref::<Self>() &&
+                code == contextual.error.code()
+            {
+                return true;
+            }
+            if let Some(source) = current.source() {
+                current = source;
+            } else {
+                break;
+            }
+        }
+        false
+    }
+}
+
+impl<E: BizError> From<StaticContextualError<E>> for ContextualError<E> {
+    fn from(error: StaticContextualError<E>) -> Self {
+        let context = error.context();
+        let location = error.location();
+        Self::with_frame(error.into_inner(), Cow::Borrowed(context), location)
+    }
+}
+
+impl<E: BizError> ContextualError<E> {
+    fn debug_message(&self) -> Cow<'static, str> {
+        if self.error.is_sensitive() {
+            Cow::Borrowed(REDACTED)
+        } else {
+            Cow::Owned(self.error.to_string())
+        }
+    }
+}
+
+impl<E: BizError> core::fmt::Debug for ContextualError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut debug_struct = f.debug_struct("ContextualError");
+        debug_struct
+            .field("type", &self.error.name())
+            .field("code", &self.error.code())
+            .field("message", &self.debug_message())
+            .field("context", &self.context())
+            .field("location", &self.location.to_string());
+        if let Some(outer) = &self.outer {
+            let frames: Vec<_> = core::iter::once(&self.frame)
+                .chain(&outer.frames)
+                .map(|frame| format!("{frame:#}"))
+                .collect();
+            debug_struct.field("frames", &frames);
+        }
+        #[cfg(feature = "backtrace")]
+        if let Some(backtrace) = &self.backtrace {
+            debug_struct.field("backtrace", backtrace);
+        }
+        debug_struct.finish()
+    }
+}
+
+/// `{}` joins the context frames with `" -> "` on one line; `{:#}` lists each
+/// frame with its location on its own line.
+impl<E: BizError> core::fmt::Display for ContextualError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}\nContext:", self.error)?;
+            for frame in self.frames() {
+                write!(f, "\n  {frame:#}")?;
+            }
+            Ok(())
+        } else {
+            write!(f, "{}\nContext: {}", self.error, self.context())
+        }
+    }
+}
+
+impl<E: BizError> Error for ContextualError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl<E: BizError> BizError for ContextualError<E> {
+    type CodeType = E::CodeType;
+
+    fn code(&self) -> Self::CodeType {
+        self.error.code()
+    }
+
+    fn name(&self) -> &str {
+        self.error.name()
+    }
+
+    fn fields(&self) -> Vec<(Cow<'static, str>, BizFieldValue)> {
+        self.error.fields()
+    }
+
+    fn is_sensitive(&self) -> bool {
+        self.error.is_sensitive()
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        BizError::description(&self.error)
+    }
+
+    fn http_status(&self) -> Option<u16> {
+        self.error.http_status()
+    }
+
+    fn grpc_code(&self) -> Option<GrpcCode> {
+        self.error.grpc_code()
+    }
+
+    fn severity(&self) -> Severity {
+        self.error.severity()
+    }
+
+    fn doc_url(&self) -> Option<&'static str> {
+        self.error.doc_url()
+    }
+
+    fn report_entry(&self) -> Option<ReportEntry<'_>> {
+        Some(ReportEntry::new(self))
+    }
+}
+
+/// Result extension trait (simplified)
+///
+/// Provides convenient methods to add business context to any Result.
+/// This trait is automatically implemented for all `Result<T, E>` types
+/// where `E` implements `Error`.
+///
+/// ## Core Methods
+///
+/// - `with_context()` - Add context and convert to `ContextualError`
+/// - `map_biz()` - Simple error type conversion
+/// - `with_context_if()` - Conditional context addition
+///
+/// ## Example
+///
+/// ```rust
+/// use bizerror::*;
+///
+/// #[derive(BizError, thiserror::Error)]
+/// pub enum MyError {
+///     #[bizcode(8001)]
+///     #[error("IO error: {0}")]
+///     IoError(#[from] std::io::Error),
+/// }
+///
+/// fn read_file() -> Result<String, ContextualError<MyError>> {
+///     std::fs::read_to_string("important.txt")
+///         .with_context("Reading critical configuration file")
+/// }
+/// ```
+pub trait ResultExt<T, E> {
+    /// Add contextual information and convert to `ContextualError`
+    ///
+    /// This method allows you to add context to any `Result` that contains
+    /// an error that can be converted to your business error type.
+    ///
+    /// The context is captured with automatic location tracking.
+    fn with_context<B>(
+        self,
+        context: impl Into<String>,
+    ) -> Result<T, ContextualError<B>>
+    where
+        B: BizError + From<E>;
+
+    /// Add a static context string and convert to `ContextualError`
+    ///
+    /// Like [`with_context`](Self::with_context), but the context is borrowed
+    /// instead of copied into a `String`.
+    fn with_static_context<B>(
+        self,
+        context: &'static str,
+    ) -> Result<T, ContextualError<B>>
+    where
+        B: BizError + From<E>;
+
+    /// Add lazily built context and convert to `ContextualError`
+    ///
+    /// The closure only runs on the error path, so formatting the context
+    /// costs nothing when the operation succeeds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bizerror::*;
+    ///
+    /// #[derive(BizError, thiserror::Error)]
+    /// pub enum MyError {
+    ///     #[bizcode(8001)]
+    ///     #[error("IO error: {0}")]
+    ///     IoError(#[from] std::io::Error),
+    /// }
+    ///
+    /// let path = "config.toml";
+    /// let result: Result<String, ContextualError<MyError>> =
+    ///     std::fs::read_to_string(path)
+    ///         .with_context_lazy(|| format!("Reading {path}"));
+    /// assert_eq!(result.unwrap_err().context(), "Reading config.toml");
+    /// ```
+    fn with_context_lazy<B, C, F>(
+        self,
+        context: F,
+    ) -> Result<T, ContextualError<B>>
+    where
+        B: BizError + From<E>,
+        C: Into<String>,
+        F: FnOnce() -> C;
+
+    /// Convert error type without adding context
+    ///
+    /// This is a convenience method that converts the error type to a business
+    /// error. It's equivalent to `.map_err(B::from)`.
+    fn map_biz<B>(self) -> Result<T, B>
+    where
+        B: BizError + From<E>;
+
+    /// Add context conditionally
+    ///
+    /// This method adds context only when the condition is true.
+    /// If the condition is false, it still converts the error type but without
+    /// context.
+    fn with_context_if<B>(
+        self,
+        condition: bool,
+        context: impl Into<String>,
+    ) -> Result<T, ContextualError<B>>
+    where
+        B: BizError + From<E>;
+
+    /// Chain operations with error conversion
+    ///
+    /// This method allows you to chain operations while converting errors
+    /// to business error types. It's a convenience method that combines
+    /// `and_then` with automatic error type conversion.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bizerror::*;
+    ///
+    /// #[derive(BizError, thiserror::Error)]
+    /// pub enum MyError {
+    ///     #[bizcode(8001)]
+    ///     #[error("IO error: {0}")]
+    ///     IoError(#[from] std::io::Error),
+    /// }
+    ///
+    /// let initial_err: Result<u32, std::io::Error> = Err(std::io::Error::new(
+    ///     std::io::ErrorKind::BrokenPipe,
+    ///     "pipe broken",
+    /// ));
+    /// let chained_err: Result<String, MyError> =
+    ///     initial_err.and_then_biz(|val| Ok(format!("Value is {val}")));
+    /// assert!(chained_err.is_err()); // true
+    /// ```
+    fn and_then_biz<U, F, B>(self, f: F) -> Result<U, B>
+    where
+        F: FnOnce(T) -> Result<U, B>,
+        B: BizError + From<E>;
+
+    /// Convert the business error inside a contextual error, keeping its
+    /// context
+    ///
+    /// Unlike [`with_context`](Self::with_context), which would wrap the
+    /// existing `ContextualError` as a new source, this moves every context
+    /// frame and location over to `ContextualError<B>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bizerror::*;
+    ///
+    /// #[derive(BizError, thiserror::Error)]
+    /// pub enum RepoError {
+    ///     #[bizcode(7001)]
+    ///     #[error("Row not found")]
+    ///     RowNotFound,
+    /// }
+    ///
+    /// #[derive(BizError, thiserror::Error)]
+    /// pub enum ServiceError {
+    ///     #[bizcode(5001)]
+    ///     #[error("Repository failure")]
+    ///     Repo(#[from] RepoError),
+    /// }
+    ///
+    /// fn load() -> Result<(), ContextualError<RepoError>> {
+    ///     Err(RepoError::RowNotFound.with_context("Loading order 7"))
+    /// }
+    ///
+    /// fn serve() -> Result<(), ContextualError<ServiceError>> {
+    ///     load().convert_contextual()
+    /// }
+    ///
+    /// let error = serve().unwrap_err();
+    /// assert_eq!(error.code(), 5001);
+    /// assert_eq!(error.context(), "Loading order 7");
+    /// ```
+    fn convert_contextual<B>(self) -> Result<T, ContextualError<B>>
+    where
+        E: IntoContextualError,
+        B: BizError + From<E::Inner>;
+}
+
+/// Conversion into a [`ContextualError`], used by
+/// [`ResultExt::convert_contextual`]
+pub trait IntoContextualError {
+    /// The wrapped business error type
+    type Inner: BizError;
+
+    /// Convert into a `ContextualError` without losing context
+    fn into_contextual_error(self) -> ContextualError<Self::Inner>;
+}
+
+impl<E: BizError> IntoContextualError for ContextualError<E> {
+    type Inner = E;
+
+    fn into_contextual_error(self) -> Self {
+        self
+    }
+}
+
+impl<E: BizError> IntoContextualError for StaticContextualError<E> {
+    type Inner = E;
+
+    fn into_contextual_error(self) -> ContextualError<E> {
+        self.into()
+    }
+}
+
+impl<T, E: Error + 'static> ResultExt<T, E> for Result<T, E> {
+    #[track_caller]
+    fn with_context<B>(
+        self,
+        context: impl Into<String>,
+    ) -> Result<T, ContextualError<B>>
+    where
+        B: BizError + From<E>,
+    {
+        // `map_err` would record the closure, not the caller, as location
+        match self {
+            Ok(value) => Ok(value),
+            Err(e) => Err(ContextualError::new(B::from(e), context)),
+        }
+    }
+
+    #[track_caller]
+    fn with_static_context<B>(
+        self,
+        context: &'static str,
+    ) -> Result<T, ContextualError<B>>
+    where
+        B: BizError + From<E>,
+    {
+        match self {
+            Ok(value) => Ok(value),
+            Err(e) => {
+                Err(ContextualError::with_static_context(B::from(e), context))
+            }
+        }
+    }
+
+    #[track_caller]
+    fn with_context_lazy<B, C, F>(
+        self,
+        context: F,
+    ) -> Result<T, ContextualError<B>>
+    where
+        B: BizError + From<E>,
+        C: Into<String>,
+        F: FnOnce() -> C,
+    {
+        match self {
+            Ok(value) => Ok(value),
+            Err(e) => Err(ContextualError::new(B::from(e), context())),
+        }
+    }
+
+    fn map_biz<B>(self) -> Result<T, B>
+    where
+        B: BizError + From<E>,
+    {
+        self.map_err(|e| B::from(e))
+    }
+
+    fn with_context_if<B>(
+        self,
+        condition: bool,
+        context: impl Into<String>,
+    ) -> Result<T, ContextualError<B>>
+    where
+        B: BizError + From<E>,
+    {
+        if condition {
+            self.with_context(context)
+        } else {
+            self.with_static_context("no context")
+        }
+    }
+
+    fn and_then_biz<U, F, B>(self, f: F) -> Result<U, B>
+    where
+        F: FnOnce(T) -> Result<U, B>,
+        B: BizError + From<E>,
+    {
+        match self {
+            Ok(t) => f(t),
+            Err(e) => Err(B::from(e)),
+        }
+    }
+
+    fn convert_contextual<B>(self) -> Result<T, ContextualError<B>>
+    where
+        E: IntoContextualError,
+        B: BizError + From<E::Inner>,
+    {
+        self.map_err(|e| e.into_contextual_error().convert())
+    }
+}
+
+/// `BizError` extension trait
+///
+/// Provides convenient methods for adding context to business errors.
+/// This trait is automatically implemented for all types that implement
+/// `BizError`.
+///
+/// ## Example
+///
+/// ```rust
+/// use bizerror::*;
+///
+/// #[derive(BizError, thiserror::Error)]
+/// pub enum ApiError {
+///     #[bizcode(4001)]
+///     #[error("Validation failed")]
+///     ValidationError,
+/// }
+///
+/// let error = ApiError::ValidationError;
+/// let contextual = error.with_context("Processing user registration");
+/// ```
+pub trait BizErrorExt: BizError + Sized {
+    /// Add context with automatic location tracking
+    ///
+    /// This method wraps the error in a `ContextualError` with the provided
+    /// context and automatic location tracking.
+    #[track_caller]
+    fn with_context(self, context: impl Into<String>) -> ContextualError<Self> {
+        ContextualError::new(self, context)
+    }
+
+    /// Add a static context string with automatic location tracking
+    ///
+    /// Like [`with_context`](Self::with_context), but the context is borrowed
+    /// instead of copied into a `String`.
+    #[track_caller]
+    fn with_static_context(
+        self,
+        context: &'static str,
+    ) -> ContextualError<Self> {
+        ContextualError::with_static_context(self, context)
+    }
+
+    /// Add context built by a closure with automatic location tracking
+    #[track_caller]
+    fn with_context_lazy<C, F>(self, context: F) -> ContextualError<Self>
+    where
+        C: Into<String>,
+        F: FnOnce() -> C,
+    {
+        ContextualError::new(self, context())
+    }
+}
+
+impl<T: BizError> BizErrorExt for T {}
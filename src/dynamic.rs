@@ -0,0 +1,300 @@
+//! Object-safe view of business errors for heterogeneous handling
+
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    string::{
+        String,
+        ToString,
+    },
+    vec::Vec,
+};
+use core::{
+    any::Any,
+    error::Error,
+    fmt,
+};
+
+use crate::{
+    BizError,
+    BizFieldValue,
+    GrpcCode,
+    ReportEntry,
+    Severity,
+};
+
+/// A business error code independent of the concrete `CodeType`
+///
+/// Integer codes of any width are stored as [`BizCode::Int`]; string codes and
+/// any other code type (via its `Display` output) as [`BizCode::Str`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BizCode {
+    Int(i128),
+    Str(Cow<'static, str>),
+}
+
+impl BizCode {
+    /// Get the code as an integer, if it is one
+    pub const fn as_int(&self) -> Option<i128> {
+        match self {
+            Self::Int(code) => Some(*code),
+            Self::Str(_) => None,
+        }
+    }
+
+    /// Get the code as a string, if it is one
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Int(_) => None,
+            Self::Str(code) => Some(code),
+        }
+    }
+
+    /// Convert a `BizError::CodeType` value
+    pub(crate) fn of<C: fmt::Display + 'static>(code: &C) -> Self {
+        let any: &dyn Any = code;
+        macro_rules! try_int {
+            ($($ty:ty),*) => {
+                $(
+                    if let Some(code) = any.downcast_ref::<$ty>() {
+                        return Self::from(*code);
+                    }
+                )*
+            };
+        }
+
+        try_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+        if let Some(code) = any.downcast_ref::<&'static str>() {
+            return Self::Str(Cow::Borrowed(code));
+        }
+        #[cfg(all(feature = "serde", feature = "std"))]
+        if let Some(code) = any.downcast_ref::<crate::RemoteCode>() {
+            return code.clone().into();
+        }
+        if let Some(code) = any.downcast_ref::<u128>() &&
+            let Ok(code) = i128::try_from(*code)
+        {
+            return Self::Int(code);
+        }
+        Self::Str(Cow::Owned(code.to_string()))
+    }
+}
+
+impl fmt::Display for BizCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(code) => write!(f, "{code}"),
+            Self::Str(code) => f.write_str(code),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for BizCode {
+                #[allow(clippy::cast_lossless, clippy::cast_possible_wrap)]
+                fn from(code: $ty) -> Self {
+                    Self::Int(code as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<&'static str> for BizCode {
+    fn from(code: &'static str) -> Self {
+        Self::Str(Cow::Borrowed(code))
+    }
+}
+
+impl From<String> for BizCode {
+    fn from(code: String) -> Self {
+        Self::Str(Cow::Owned(code))
+    }
+}
+
+/// Rebuild a business error from its code
+///
+/// `#[bizconfig(serde)]` implements it for the unit variants of an enum,
+/// which lets `RemoteBizError::try_into_typed` map received errors back to
+/// local ones. Implement it by hand to cover variants with fields.
+pub trait FromBizCode: BizError + Sized {
+    /// Get the error with `code`, if there is one
+    fn from_biz_code(code: &BizCode) -> Option<Self>;
+}
+
+/// Object-safe companion of [`BizError`]
+///
+/// `BizError` has an associated `CodeType`, so it cannot be used as
+/// `dyn BizError`. `DynBizError` is implemented for every `BizError` and
+/// exposes the code as a [`BizCode`], which makes
+/// `Box<dyn DynBizError>` usable for errors from different enums.
+///
+/// The trait methods carry a `biz_` prefix so they don't clash with
+/// `BizError` on concrete types; on `dyn DynBizError` the shorter
+/// `code` and `name` are available as well.
+///
+/// # Example
+///
+/// ```rust
+/// use bizerror::*;
+///
+/// #[derive(BizError, thiserror::Error)]
+/// pub enum UserError {
+///     #[bizcode(4001)]
+///     #[error("User not found")]
+///     NotFound,
+/// }
+///
+/// #[derive(BizError, thiserror::Error)]
+/// #[bizconfig(code_type = "&'static str")]
+/// pub enum PaymentError {
+///     #[bizcode("PAY_DECLINED")]
+///     #[error("Payment declined")]
+///     Declined,
+/// }
+///
+/// let errors: Vec<Box<dyn DynBizError>> =
+///     vec![UserError::NotFound.into(), PaymentError::Declined.into()];
+///
+/// assert_eq!(errors[0].code(), BizCode::Int(4001));
+/// assert_eq!(errors[1].code().as_str(), Some("PAY_DECLINED"));
+/// assert_eq!(errors[1].name(), "Declined");
+/// assert!(errors[0].downcast_ref::<UserError>().is_some());
+/// ```
+pub trait DynBizError: Error + Any + Send + Sync {
+    /// Get the business error code
+    fn biz_code(&self) -> BizCode;
+
+    /// Get the business error name (variant name for derived errors)
+    fn biz_name(&self) -> &str;
+
+    /// Get the name of the concrete error type
+    fn type_name(&self) -> &'static str;
+
+    /// Get the structured fields, see [`BizError::fields`]
+    fn biz_fields(&self) -> Vec<(Cow<'static, str>, BizFieldValue)>;
+
+    /// Check if the error carries sensitive data, see
+    /// [`BizError::is_sensitive`]
+    fn biz_is_sensitive(&self) -> bool;
+
+    /// Get the HTTP status set by the variant, see [`BizError::http_status`]
+    fn biz_http_status(&self) -> Option<u16>;
+
+    /// Get the gRPC code set by the variant, see [`BizError::grpc_code`]
+    fn biz_grpc_code(&self) -> Option<GrpcCode>;
+
+    /// Get the severity, see [`BizError::severity`]
+    fn biz_severity(&self) -> Severity;
+
+    /// Get the documentation URL, see [`BizError::doc_url`]
+    fn biz_doc_url(&self) -> Option<&'static str>;
+
+    #[doc(hidden)]
+    fn biz_report_entry(&self) -> Option<ReportEntry<'_>> {
+        None
+    }
+}
+
+impl<E: BizError> DynBizError for E {
+    fn biz_code(&self) -> BizCode {
+        BizCode::of(&self.code())
+    }
+
+    fn biz_name(&self) -> &str {
+        self.name()
+    }
+
+    fn type_name(&self) -> &'static str {
+        core::any::type_name::<E>()
+    }
+
+    fn biz_fields(&self) -> Vec<(Cow<'static, str>, BizFieldValue)> {
+        self.fields()
+    }
+
+    fn biz_is_sensitive(&self) -> bool {
+        self.is_sensitive()
+    }
+
+    fn biz_http_status(&self) -> Option<u16> {
+        self.http_status()
+    }
+
+    fn biz_grpc_code(&self) -> Option<GrpcCode> {
+        self.grpc_code()
+    }
+
+    fn biz_severity(&self) -> Severity {
+        self.severity()
+    }
+
+    fn biz_doc_url(&self) -> Option<&'static str> {
+        self.doc_url()
+    }
+
+    fn biz_report_entry(&self) -> Option<ReportEntry<'_>> {
+        self.report_entry()
+    }
+}
+
+impl dyn DynBizError {
+    /// Get the business error code
+    pub fn code(&self) -> BizCode {
+        self.biz_code()
+    }
+
+    /// Get the business error name
+    pub fn name(&self) -> &str {
+        self.biz_name()
+    }
+
+    /// Check if the concrete error type is `T`
+    pub fn is<T: DynBizError>(&self) -> bool {
+        let any: &dyn Any = self;
+        any.is::<T>()
+    }
+
+    /// Get a reference to the concrete error if it is of type `T`
+    pub fn downcast_ref<T: DynBizError>(&self) -> Option<&T> {
+        let any: &dyn Any = self;
+        any.downcast_ref()
+    }
+
+    /// Get a mutable reference to the concrete error if it is of type `T`
+    pub fn downcast_mut<T: DynBizError>(&mut self) -> Option<&mut T> {
+        let any: &mut dyn Any = self;
+        any.downcast_mut()
+    }
+
+    /// Convert back to the concrete error type, or return `self` unchanged
+    ///
+    /// # Errors
+    ///
+    /// Returns the original box if the concrete error type is not `T`.
+    pub fn downcast<T: DynBizError>(
+        self: Box<Self>,
+    ) -> Result<Box<T>, Box<Self>> {
+        if self.is::<T>() {
+            let any: Box<dyn Any> = self;
+            Ok(any.downcast().unwrap_or_else(|_| unreachable!()))
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Convert into a boxed standard error
+    pub fn into_error(self: Box<Self>) -> Box<dyn Error + Send + Sync> {
+        self
+    }
+}
+
+impl<E: BizError> From<E> for Box<dyn DynBizError> {
+    fn from(error: E) -> Self {
+        Box::new(error)
+    }
+}
@@ -0,0 +1,158 @@
+//! Structured field values exposed by [`BizError::fields`]
+
+use alloc::{
+    borrow::{
+        Cow,
+        ToOwned,
+    },
+    string::{
+        String,
+        ToString,
+    },
+};
+
+#[cfg(doc)]
+use crate::BizError;
+use crate::{
+    REDACTED,
+    Redacted,
+};
+
+/// Structured value of a business error field
+///
+/// Produced by [`BizError::fields`] so that logs and serializers can emit
+/// error data as typed key/value pairs.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BizFieldValue {
+    /// Boolean value
+    Bool(bool),
+    /// Signed integer value
+    Int(i64),
+    /// Unsigned integer value
+    UInt(u64),
+    /// Floating point value
+    Float(f64),
+    /// String value (also used for integers that don't fit 64 bits)
+    Str(String),
+    /// Value hidden by `#[biz(sensitive)]` or [`Redacted`], rendered as
+    /// `<redacted>`
+    Redacted,
+}
+
+impl core::fmt::Display for BizFieldValue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Int(value) => write!(f, "{value}"),
+            Self::UInt(value) => write!(f, "{value}"),
+            Self::Float(value) => write!(f, "{value}"),
+            Self::Str(value) => write!(f, "{value}"),
+            Self::Redacted => f.write_str(REDACTED),
+        }
+    }
+}
+
+/// Conversion of a field type into a [`BizFieldValue`]
+///
+/// Implemented for primitives and string types. Implement it for your own
+/// field types to have them included in [`BizError::fields`].
+pub trait ToBizFieldValue {
+    /// Convert the field into a structured value
+    fn to_biz_field_value(&self) -> BizFieldValue;
+}
+
+macro_rules! impl_to_biz_field_value {
+    ($variant:ident as $target:ty: $($ty:ty),*) => {
+        $(
+            impl ToBizFieldValue for $ty {
+                fn to_biz_field_value(&self) -> BizFieldValue {
+                    BizFieldValue::$variant(<$target>::from(*self))
+                }
+            }
+        )*
+    };
+}
+
+impl_to_biz_field_value!(Bool as bool: bool);
+impl_to_biz_field_value!(Int as i64: i8, i16, i32, i64);
+impl_to_biz_field_value!(UInt as u64: u8, u16, u32, u64);
+impl_to_biz_field_value!(Float as f64: f32, f64);
+
+macro_rules! impl_to_biz_field_value_fallible {
+    ($variant:ident as $target:ty: $($ty:ty),*) => {
+        $(
+            impl ToBizFieldValue for $ty {
+                fn to_biz_field_value(&self) -> BizFieldValue {
+                    <$target>::try_from(*self).map_or_else(
+                        |_| BizFieldValue::Str(self.to_string()),
+                        BizFieldValue::$variant,
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_to_biz_field_value_fallible!(Int as i64: i128, isize);
+impl_to_biz_field_value_fallible!(UInt as u64: u128, usize);
+
+impl ToBizFieldValue for char {
+    fn to_biz_field_value(&self) -> BizFieldValue {
+        BizFieldValue::Str(self.to_string())
+    }
+}
+
+impl ToBizFieldValue for str {
+    fn to_biz_field_value(&self) -> BizFieldValue {
+        BizFieldValue::Str(self.to_owned())
+    }
+}
+
+impl ToBizFieldValue for String {
+    fn to_biz_field_value(&self) -> BizFieldValue {
+        BizFieldValue::Str(self.clone())
+    }
+}
+
+impl ToBizFieldValue for Cow<'_, str> {
+    fn to_biz_field_value(&self) -> BizFieldValue {
+        BizFieldValue::Str(self.to_string())
+    }
+}
+
+impl<T: ToBizFieldValue + ?Sized> ToBizFieldValue for &T {
+    fn to_biz_field_value(&self) -> BizFieldValue {
+        (**self).to_biz_field_value()
+    }
+}
+
+impl<T> ToBizFieldValue for Redacted<T> {
+    fn to_biz_field_value(&self) -> BizFieldValue {
+        BizFieldValue::Redacted
+    }
+}
+
+/// Autoref specialization behind [`BizError::fields`] in derived impls:
+/// picks up [`ToBizFieldValue`] where implemented and skips the field
+/// otherwise.
+pub struct FieldProbe<'a, T: ?Sized>(pub &'a T);
+
+pub trait ViaToBizFieldValue {
+    fn biz_field_value(&self) -> Option<BizFieldValue>;
+}
+
+impl<T: ToBizFieldValue + ?Sized> ViaToBizFieldValue for FieldProbe<'_, T> {
+    fn biz_field_value(&self) -> Option<BizFieldValue> {
+        Some(self.0.to_biz_field_value())
+    }
+}
+
+pub trait ViaFallback {
+    fn biz_field_value(&self) -> Option<BizFieldValue>;
+}
+
+impl<T: ?Sized> ViaFallback for &FieldProbe<'_, T> {
+    fn biz_field_value(&self) -> Option<BizFieldValue> {
+        None
+    }
+}
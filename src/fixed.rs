@@ -0,0 +1,454 @@
+//! Allocation-free error context and aggregation for embedded targets
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    borrow::Cow,
+    vec::Vec,
+};
+use core::{
+    error::Error,
+    panic::Location,
+};
+
+#[cfg(feature = "alloc")]
+use crate::BizFieldValue;
+use crate::{
+    __private::DisplayAsDebug,
+    BizError,
+    GrpcCode,
+    REDACTED,
+    Severity,
+};
+
+/// Contextual error wrapper that never allocates
+///
+/// The `no_std` counterpart of `ContextualError`: the context is a
+/// `&'static str` and the location is captured with `#[track_caller]`, so the
+/// wrapper is just the error plus two pointers.
+///
+/// ## Example
+///
+/// ```rust
+/// use bizerror::*;
+///
+/// #[derive(BizError, thiserror::Error)]
+/// pub enum SensorError {
+///     #[bizcode(3001)]
+///     #[error("Sensor timed out")]
+///     Timeout,
+/// }
+///
+/// let error = StaticContextualError::new(SensorError::Timeout, "Reading IMU");
+/// assert_eq!(error.context(), "Reading IMU");
+/// assert_eq!(error.code(), 3001);
+/// ```
+pub struct StaticContextualError<E: BizError> {
+    error:    E,
+    context:  &'static str,
+    location: &'static Location<'static>,
+}
+
+impl<E: BizError> StaticContextualError<E> {
+    /// Create a new contextual error with automatic location tracking
+    #[track_caller]
+    pub const fn new(error: E, context: &'static str) -> Self {
+        Self {
+            error,
+            context,
+            location: Location::caller(),
+        }
+    }
+
+    /// Get the original error
+    pub const fn inner(&self) -> &E {
+        &self.error
+    }
+
+    /// Get the context
+    pub const fn context(&self) -> &'static str {
+        self.context
+    }
+
+    /// Get the location where the context was added
+    pub const fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    /// Unwrap the contextual error, returning the inner error
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E: BizError> core::fmt::Debug for StaticContextualError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut debug_struct = f.debug_struct("StaticContextualError");
+        debug_struct
+            .field("type", &self.error.name())
+            .field("code", &self.error.code());
+        if self.error.is_sensitive() {
+            debug_struct.field("message", &REDACTED);
+        } else {
+            debug_struct.field("message", &DisplayAsDebug(&self.error));
+        }
+        debug_struct
+            .field("context", &self.context)
+            .field(
+                "location",
+                &format_args!(
+                    "{}:{}:{}",
+                    self.location.file(),
+                    self.location.line(),
+                    self.location.column()
+                ),
+            )
+            .finish()
+    }
+}
+
+impl<E: BizError> core::fmt::Display for StaticContextualError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}\nContext: {}", self.error, self.context)
+    }
+}
+
+impl<E: BizError> Error for StaticContextualError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl<E: BizError> BizError for StaticContextualError<E> {
+    type CodeType = E::CodeType;
+
+    fn code(&self) -> Self::CodeType {
+        self.error.code()
+    }
+
+    fn name(&self) -> &str {
+        self.error.name()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn fields(&self) -> Vec<(Cow<'static, str>, BizFieldValue)> {
+        self.error.fields()
+    }
+
+    fn is_sensitive(&self) -> bool {
+        self.error.is_sensitive()
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        BizError::description(&self.error)
+    }
+
+    fn http_status(&self) -> Option<u16> {
+        self.error.http_status()
+    }
+
+    fn grpc_code(&self) -> Option<GrpcCode> {
+        self.error.grpc_code()
+    }
+
+    fn severity(&self) -> Severity {
+        self.error.severity()
+    }
+
+    fn doc_url(&self) -> Option<&'static str> {
+        self.error.doc_url()
+    }
+}
+
+/// Fixed-capacity business errors collection
+///
+/// The allocation-free counterpart of `BizErrors`: it stores up to `N` errors
+/// inline and counts, rather than stores, anything pushed beyond that.
+///
+/// ## Example
+///
+/// ```rust
+/// use bizerror::*;
+///
+/// #[derive(BizError, thiserror::Error)]
+/// pub enum ValidationError {
+///     #[bizcode(4001)]
+///     #[error("Value out of range")]
+///     OutOfRange,
+/// }
+///
+/// let mut errors = BizErrorsArray::<ValidationError, 2>::new();
+/// errors.push_simple(ValidationError::OutOfRange);
+/// errors.push_with_context(ValidationError::OutOfRange, "Checking pressure");
+/// errors.push_simple(ValidationError::OutOfRange);
+///
+/// assert_eq!(errors.len(), 2);
+/// assert_eq!(errors.overflow(), 1);
+/// assert_eq!(errors.total(), 3);
+/// ```
+pub struct BizErrorsArray<E: BizError, const N: usize> {
+    errors:   [Option<StaticContextualError<E>>; N],
+    len:      usize,
+    overflow: usize,
+}
+
+impl<E: BizError, const N: usize> BizErrorsArray<E, N> {
+    /// Create a new empty error collection
+    pub const fn new() -> Self {
+        Self {
+            errors:   [const { None }; N],
+            len:      0,
+            overflow: 0,
+        }
+    }
+
+    /// Add a contextual error to the collection
+    ///
+    /// Returns `false` if the collection is full; the error is then dropped
+    /// and only counted in [`overflow`](Self::overflow).
+    pub fn push(&mut self, error: StaticContextualError<E>) -> bool {
+        if let Some(slot) = self.errors.get_mut(self.len) {
+            *slot = Some(error);
+            self.len += 1;
+            true
+        } else {
+            self.overflow += 1;
+            false
+        }
+    }
+
+    /// Add a simple business error to the collection
+    #[track_caller]
+    pub fn push_simple(&mut self, error: E) -> bool {
+        self.push(StaticContextualError::new(error, ""))
+    }
+
+    /// Add a business error with context to the collection
+    #[track_caller]
+    pub fn push_with_context(
+        &mut self,
+        error: E,
+        context: &'static str,
+    ) -> bool {
+        self.push(StaticContextualError::new(error, context))
+    }
+
+    /// Get the number of errors stored in the collection
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the error collection is empty
+    ///
+    /// A collection that only overflowed is not empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0 && self.overflow == 0
+    }
+
+    /// Get the maximum number of errors the collection can store
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Check if the collection has no room left
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Get the number of errors dropped because the collection was full
+    pub const fn overflow(&self) -> usize {
+        self.overflow
+    }
+
+    /// Get the number of errors pushed, including dropped ones
+    pub const fn total(&self) -> usize {
+        self.len + self.overflow
+    }
+
+    /// Get an iterator over the stored errors
+    pub fn iter(&self) -> impl Iterator<Item = &StaticContextualError<E>> {
+        self.errors.iter().map_while(Option::as_ref)
+    }
+
+    /// Get the error at `index`
+    pub fn get(&self, index: usize) -> Option<&StaticContextualError<E>> {
+        self.errors.get(index)?.as_ref()
+    }
+
+    /// Get the first error in the collection
+    pub fn first(&self) -> Option<&StaticContextualError<E>> {
+        self.get(0)
+    }
+
+    /// Get the last stored error in the collection
+    pub fn last(&self) -> Option<&StaticContextualError<E>> {
+        self.get(self.len.checked_sub(1)?)
+    }
+
+    /// Check if any stored error has the specified code
+    pub fn contains_code<C>(&self, code: C) -> bool
+    where
+        C: PartialEq<E::CodeType> + Copy,
+    {
+        self.iter().any(|error| code == error.code())
+    }
+}
+
+impl<E: BizError, const N: usize> Default for BizErrorsArray<E, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: BizError, const N: usize> core::fmt::Debug for BizErrorsArray<E, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        struct Errors<'a, E: BizError, const N: usize>(
+            &'a BizErrorsArray<E, N>,
+        );
+
+        impl<E: BizError, const N: usize> core::fmt::Debug for Errors<'_, E, N> {
+            fn fmt(
+                &self,
+                f: &mut core::fmt::Formatter<'_>,
+            ) -> core::fmt::Result {
+                f.debug_list().entries(self.0.iter()).finish()
+            }
+        }
+
+        let mut debug_struct = f.debug_struct("BizErrorsArray");
+        debug_struct
+            .field("count", &self.len)
+            .field("capacity", &N)
+            .field("errors", &Errors(self));
+        if self.overflow > 0 {
+            debug_struct.field("overflow", &self.overflow);
+        }
+        debug_struct.finish()
+    }
+}
+
+impl<E: BizError, const N: usize> core::fmt::Display for BizErrorsArray<E, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "No errors");
+        }
+        if self.len == 1 && self.overflow == 0 {
+            return write!(f, "{}", self.errors[0].as_ref().expect("stored"));
+        }
+        writeln!(f, "Multiple errors occurred ({} total):", self.total())?;
+        for (i, error) in self.iter().enumerate() {
+            writeln!(f, "  {}. {}", i + 1, error)?;
+        }
+        if self.overflow > 0 {
+            writeln!(f, "  ... and {} more not recorded", self.overflow)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: BizError, const N: usize> Error for BizErrorsArray<E, N> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        // Return the first error as the source
+        self.first().map(|e| e as &dyn Error)
+    }
+}
+
+impl<E: BizError, const N: usize> BizError for BizErrorsArray<E, N> {
+    type CodeType = E::CodeType;
+
+    fn code(&self) -> Self::CodeType {
+        // Return the code of the first error
+        self.first()
+            .map_or_else(|| panic!("BizErrorsArray is empty"), BizError::code)
+    }
+
+    fn name(&self) -> &'static str {
+        "BizErrorsArray"
+    }
+}
+
+impl<'a, E: BizError, const N: usize> IntoIterator
+    for &'a BizErrorsArray<E, N>
+{
+    type Item = &'a StaticContextualError<E>;
+    type IntoIter = core::iter::MapWhile<
+        core::slice::Iter<'a, Option<StaticContextualError<E>>>,
+        fn(
+            &'a Option<StaticContextualError<E>>,
+        ) -> Option<&'a StaticContextualError<E>>,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter().map_while(Option::as_ref)
+    }
+}
+
+impl<E: BizError, const N: usize> FromIterator<StaticContextualError<E>>
+    for BizErrorsArray<E, N>
+{
+    fn from_iter<T: IntoIterator<Item = StaticContextualError<E>>>(
+        iter: T,
+    ) -> Self {
+        let mut errors = Self::new();
+        for error in iter {
+            errors.push(error);
+        }
+        errors
+    }
+}
+
+impl<E: BizError, const N: usize> FromIterator<E> for BizErrorsArray<E, N> {
+    #[track_caller]
+    fn from_iter<T: IntoIterator<Item = E>>(iter: T) -> Self {
+        let mut errors = Self::new();
+        for error in iter {
+            errors.push_simple(error);
+        }
+        errors
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<E: BizError> defmt::Format for StaticContextualError<E> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        let code = defmt::Display2Format(&self.error.code());
+        if self.error.is_sensitive() {
+            defmt::write!(
+                f,
+                "{}[{}]: {=str} ({=str} at {=str}:{=u32})",
+                self.error.name(),
+                code,
+                REDACTED,
+                self.context,
+                self.location.file(),
+                self.location.line()
+            );
+        } else {
+            defmt::write!(
+                f,
+                "{}[{}]: {} ({=str} at {=str}:{=u32})",
+                self.error.name(),
+                code,
+                defmt::Display2Format(&self.error),
+                self.context,
+                self.location.file(),
+                self.location.line()
+            );
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<E: BizError, const N: usize> defmt::Format for BizErrorsArray<E, N> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "BizErrorsArray({=usize}/{=usize}, overflow {=usize})",
+            self.len,
+            N,
+            self.overflow
+        );
+        for error in self {
+            defmt::write!(f, "\n  {}", error);
+        }
+    }
+}
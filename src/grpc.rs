@@ -0,0 +1,439 @@
+//! gRPC status codes for business errors and, behind the `tonic` feature,
+//! conversions to and from `tonic::Status`
+
+use core::fmt;
+
+/// A canonical gRPC status code, set on a variant with `#[bizgrpc(...)]`
+///
+/// `Ok` is left out, as a business error never succeeds. The discriminants
+/// are the gRPC wire values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(i32)]
+pub enum GrpcCode {
+    Cancelled = 1,
+    Unknown = 2,
+    InvalidArgument = 3,
+    DeadlineExceeded = 4,
+    NotFound = 5,
+    AlreadyExists = 6,
+    PermissionDenied = 7,
+    ResourceExhausted = 8,
+    FailedPrecondition = 9,
+    Aborted = 10,
+    OutOfRange = 11,
+    Unimplemented = 12,
+    Internal = 13,
+    Unavailable = 14,
+    DataLoss = 15,
+    Unauthenticated = 16,
+}
+
+impl GrpcCode {
+    /// Get the code for an HTTP status, following the mapping of the
+    /// `google.rpc.Code` documentation
+    ///
+    /// Other client errors map to `FailedPrecondition`, other server errors
+    /// to `Internal` and anything else to `Unknown`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bizerror::GrpcCode;
+    ///
+    /// assert_eq!(GrpcCode::from_http(404), GrpcCode::NotFound);
+    /// assert_eq!(GrpcCode::from_http(422), GrpcCode::FailedPrecondition);
+    /// assert_eq!(GrpcCode::from_http(500), GrpcCode::Internal);
+    /// ```
+    pub const fn from_http(status: u16) -> Self {
+        match status {
+            400 => Self::InvalidArgument,
+            401 => Self::Unauthenticated,
+            403 => Self::PermissionDenied,
+            404 => Self::NotFound,
+            409 => Self::Aborted,
+            416 => Self::OutOfRange,
+            429 => Self::ResourceExhausted,
+            499 => Self::Cancelled,
+            501 => Self::Unimplemented,
+            503 => Self::Unavailable,
+            504 => Self::DeadlineExceeded,
+            _ => match status / 100 {
+                4 => Self::FailedPrecondition,
+                5 => Self::Internal,
+                _ => Self::Unknown,
+            },
+        }
+    }
+
+    /// Get the code from its wire value; `None` for `Ok` and unknown values
+    pub const fn from_i32(value: i32) -> Option<Self> {
+        Some(match value {
+            1 => Self::Cancelled,
+            2 => Self::Unknown,
+            3 => Self::InvalidArgument,
+            4 => Self::DeadlineExceeded,
+            5 => Self::NotFound,
+            6 => Self::AlreadyExists,
+            7 => Self::PermissionDenied,
+            8 => Self::ResourceExhausted,
+            9 => Self::FailedPrecondition,
+            10 => Self::Aborted,
+            11 => Self::OutOfRange,
+            12 => Self::Unimplemented,
+            13 => Self::Internal,
+            14 => Self::Unavailable,
+            15 => Self::DataLoss,
+            16 => Self::Unauthenticated,
+            _ => return None,
+        })
+    }
+
+    /// Get the wire value
+    pub const fn as_i32(self) -> i32 {
+        self as i32
+    }
+
+    /// Get the name as written in `#[bizgrpc(...)]`, e.g. `not_found`
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Cancelled => "cancelled",
+            Self::Unknown => "unknown",
+            Self::InvalidArgument => "invalid_argument",
+            Self::DeadlineExceeded => "deadline_exceeded",
+            Self::NotFound => "not_found",
+            Self::AlreadyExists => "already_exists",
+            Self::PermissionDenied => "permission_denied",
+            Self::ResourceExhausted => "resource_exhausted",
+            Self::FailedPrecondition => "failed_precondition",
+            Self::Aborted => "aborted",
+            Self::OutOfRange => "out_of_range",
+            Self::Unimplemented => "unimplemented",
+            Self::Internal => "internal",
+            Self::Unavailable => "unavailable",
+            Self::DataLoss => "data_loss",
+            Self::Unauthenticated => "unauthenticated",
+        }
+    }
+}
+
+impl fmt::Display for GrpcCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "tonic")]
+pub use status::grpc_status;
+
+#[cfg(feature = "tonic")]
+mod status {
+    use alloc::{
+        string::{
+            String,
+            ToString,
+        },
+        vec::Vec,
+    };
+    use core::fmt::Write;
+    use std::collections::HashMap;
+
+    use tonic::{
+        Code,
+        Status,
+    };
+    use tonic_types::{
+        ErrorDetails,
+        StatusExt,
+    };
+
+    use super::GrpcCode;
+    use crate::{
+        BizCode,
+        BizError,
+        BizErrors,
+        BizFieldValue,
+        ContextualError,
+        ProblemConfig,
+        REDACTED,
+        RemoteBizError,
+        RemoteCause,
+        RemoteCode,
+        RemoteFrame,
+        ReportEntry,
+        Reportable,
+    };
+
+    /// Prefix of the `ErrorInfo` metadata keys that are not fields
+    const RESERVED_PREFIX: &str = "bizerror_";
+    /// The `ErrorInfo` metadata key holding the business code
+    const CODE_KEY: &str = "bizerror_code";
+    /// The `ErrorInfo` metadata key telling `int` codes from `str` ones
+    const CODE_KIND_KEY: &str = "bizerror_code_kind";
+    /// The `ErrorInfo` metadata key set to `true` for sensitive errors
+    const SENSITIVE_KEY: &str = "bizerror_sensitive";
+    /// Prefix of the `ErrorInfo` metadata keys holding an escaped field name
+    const FIELD_PREFIX: &str = "field_";
+    /// Prefix of the `DebugInfo` stack entries holding a context frame
+    const CONTEXT_PREFIX: &str = "context: ";
+    /// Prefix of the `DebugInfo` stack entries holding a source message
+    const SOURCE_PREFIX: &str = "caused by: ";
+
+    impl From<GrpcCode> for Code {
+        fn from(code: GrpcCode) -> Self {
+            Self::from_i32(code.as_i32())
+        }
+    }
+
+    /// Convert business errors into a `tonic::Status`
+    ///
+    /// The status code comes from `#[bizgrpc(...)]` or, failing that, from
+    /// the HTTP status of the process-wide [`ProblemConfig`] through
+    /// [`GrpcCode::from_http`]. The message is the error message, redacted
+    /// for sensitive errors.
+    ///
+    /// A `google.rpc.ErrorInfo` detail carries the business name as `reason`,
+    /// the crate defining the error as `domain`, and the structured fields
+    /// and the business code, under `bizerror_code`, as `metadata`, with
+    /// `bizerror_code_kind` set to `int` or `str` so that string codes like
+    /// `"404"` stay strings, and `bizerror_sensitive` set to `true` for
+    /// sensitive errors. Metadata keys
+    /// must match `[a-z][a-zA-Z0-9-_]+`, so other field names, like the `0`
+    /// of a tuple variant, get a `field_` prefix and their other characters
+    /// are escaped as `-{hex}-`. Unless
+    /// the configuration is in [`production`](ProblemConfig::production)
+    /// mode, a `google.rpc.DebugInfo` detail has a stack entry for each
+    /// context frame, prefixed `context: `, followed by one for each source,
+    /// prefixed `caused by: `.
+    ///
+    /// For several errors the first one sets the code and the details, and
+    /// the message joins every message. Plain business errors convert as
+    /// `grpc_status(&error as &dyn DynBizError)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bizerror::*;
+    ///
+    /// #[derive(BizError, thiserror::Error)]
+    /// pub enum OrderError {
+    ///     #[bizcode(4004)]
+    ///     #[bizgrpc(not_found)]
+    ///     #[error("Order not found")]
+    ///     NotFound,
+    /// }
+    ///
+    /// fn load_order() -> Result<String, ContextualError<OrderError>> {
+    ///     Err(OrderError::NotFound.with_context("Loading order"))
+    /// }
+    ///
+    /// fn get_order() -> Result<String, tonic::Status> {
+    ///     Ok(load_order()?)
+    /// }
+    ///
+    /// let status = get_order().unwrap_err();
+    /// assert_eq!(status.code(), tonic::Code::NotFound);
+    /// assert_eq!(status.message(), "Order not found");
+    ///
+    /// let remote = RemoteBizError::from_grpc_status(&status).unwrap();
+    /// assert_eq!(remote.code(), RemoteCode::Int(4004));
+    /// assert_eq!(remote.name(), "NotFound");
+    /// assert_eq!(remote.context()[0].message(), "Loading order");
+    /// ```
+    pub fn grpc_status<R: Reportable + ?Sized>(error: &R) -> Status {
+        let entries = error.report_entries();
+        let Some(first) = entries.first() else {
+            return Status::unknown("no business error");
+        };
+        let message = if let [entry] = entries.as_slice() {
+            entry.message().into_owned()
+        } else {
+            entries
+                .iter()
+                .map(ReportEntry::message)
+                .collect::<Vec<_>>()
+                .join("; ")
+        };
+        Status::with_error_details(
+            entry_code(first).into(),
+            message,
+            entry_details(first),
+        )
+    }
+
+    fn entry_code(entry: &ReportEntry<'_>) -> GrpcCode {
+        let error = entry.biz_error();
+        error.biz_grpc_code().unwrap_or_else(|| {
+            GrpcCode::from_http(ProblemConfig::current().status_of(error))
+        })
+    }
+
+    fn entry_details(entry: &ReportEntry<'_>) -> ErrorDetails {
+        let type_name = entry.biz_error().type_name();
+        let domain = type_name.split("::").next().unwrap_or(type_name);
+        let mut metadata: HashMap<String, String> = entry
+            .fields()
+            .into_iter()
+            .map(|(name, value)| (metadata_key(&name), value.to_string()))
+            .collect();
+        let code = entry.code();
+        let kind = match code {
+            BizCode::Int(_) => "int",
+            BizCode::Str(_) => "str",
+        };
+        metadata.insert(CODE_KEY.to_string(), code.to_string());
+        metadata.insert(CODE_KIND_KEY.to_string(), kind.to_string());
+        if entry.biz_error().biz_is_sensitive() {
+            metadata.insert(SENSITIVE_KEY.to_string(), true.to_string());
+        }
+
+        let mut details =
+            ErrorDetails::with_error_info(entry.name(), domain, metadata);
+        let frames = entry.frames();
+        let causes = entry.causes();
+        if ProblemConfig::current().exposes_context() &&
+            !(frames.is_empty() && causes.is_empty())
+        {
+            let frames = frames
+                .iter()
+                .map(|frame| alloc::format!("{CONTEXT_PREFIX}{frame:#}"));
+            let sources = causes.iter().map(|cause| {
+                alloc::format!("{SOURCE_PREFIX}{}", cause.message())
+            });
+            details
+                .set_debug_info(frames.chain(sources).collect::<Vec<_>>(), "");
+        }
+        details
+    }
+
+    /// Map a field name onto the charset of `ErrorInfo` metadata keys
+    fn metadata_key(name: &str) -> String {
+        let plain = name.len() > 1 &&
+            name.starts_with(|c: char| c.is_ascii_lowercase()) &&
+            name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') &&
+            !name.starts_with(FIELD_PREFIX) &&
+            !name.starts_with(RESERVED_PREFIX);
+        if plain {
+            return name.to_string();
+        }
+        let mut key = String::from(FIELD_PREFIX);
+        for c in name.chars() {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                key.push(c);
+            } else {
+                let _ = write!(key, "-{:x}-", u32::from(c));
+            }
+        }
+        key
+    }
+
+    /// Recover the field name from a metadata key built by [`metadata_key`]
+    fn field_name(key: &str) -> String {
+        let Some(escaped) = key.strip_prefix(FIELD_PREFIX) else {
+            return key.to_string();
+        };
+        let mut name = String::new();
+        // Escaped characters sit between the odd dashes
+        for (index, part) in escaped.split('-').enumerate() {
+            if index % 2 == 0 {
+                name.push_str(part);
+                continue;
+            }
+            match u32::from_str_radix(part, 16).ok().and_then(char::from_u32) {
+                Some(c) => name.push(c),
+                None => return key.to_string(),
+            }
+        }
+        name
+    }
+
+    impl<E: BizError> From<ContextualError<E>> for Status {
+        fn from(error: ContextualError<E>) -> Self {
+            grpc_status(&error)
+        }
+    }
+
+    impl<E: BizError> From<BizErrors<E>> for Status {
+        fn from(errors: BizErrors<E>) -> Self {
+            grpc_status(&errors)
+        }
+    }
+
+    impl RemoteBizError {
+        /// Rebuild a business error from a `tonic::Status` built by
+        /// [`grpc_status`]
+        ///
+        /// Returns `None` if the status has no `google.rpc.ErrorInfo` detail
+        /// with a business code. Metadata keys not starting with `bizerror_`
+        /// become string fields, with the field names unescaped, in name
+        /// order.
+        pub fn from_grpc_status(status: &Status) -> Option<Self> {
+            let details = status.get_error_details();
+            let info = details.error_info()?;
+            let code = info.metadata.get(CODE_KEY)?;
+            let code =
+                match info.metadata.get(CODE_KIND_KEY).map(String::as_str) {
+                    Some("str") => RemoteCode::Str(code.as_str().into()),
+                    // Without a kind, integer-like codes are taken as integers
+                    _ => code.parse().map_or_else(
+                        |_| RemoteCode::Str(code.as_str().into()),
+                        RemoteCode::Int,
+                    ),
+                };
+
+            let mut fields: Vec<_> = info
+                .metadata
+                .iter()
+                .filter(|(key, _)| !key.starts_with(RESERVED_PREFIX))
+                .map(|(key, value)| {
+                    let value = if value == REDACTED {
+                        BizFieldValue::Redacted
+                    } else {
+                        BizFieldValue::Str(value.clone())
+                    };
+                    (field_name(key), value)
+                })
+                .collect();
+            fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            let mut context = Vec::new();
+            let mut sources = Vec::new();
+            for entry in details
+                .debug_info()
+                .map(|debug| debug.stack_entries.as_slice())
+                .unwrap_or_default()
+            {
+                if let Some(source) = entry.strip_prefix(SOURCE_PREFIX) {
+                    sources.push(RemoteCause::message_only(source.to_string()));
+                    continue;
+                }
+                let frame = entry.strip_prefix(CONTEXT_PREFIX).unwrap_or(entry);
+                context.push(match frame.rsplit_once(", at ") {
+                    Some((message, location)) => RemoteFrame {
+                        message:  message.to_string(),
+                        location: Some(location.to_string()),
+                    },
+                    None => RemoteFrame {
+                        message:  frame.to_string(),
+                        location: None,
+                    },
+                });
+            }
+            let location =
+                context.last().and_then(|frame| frame.location.clone());
+
+            Some(
+                Self::from_parts(
+                    code,
+                    info.reason.clone(),
+                    status.message().to_string(),
+                    fields,
+                    context,
+                    location,
+                    sources,
+                )
+                .sensitive(info.metadata.contains_key(SENSITIVE_KEY)),
+            )
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! HTTP responses for business errors, shared by the web framework
+//! integrations
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{
+    BizError,
+    BizErrors,
+    ContextualError,
+    ProblemConfig,
+    ProblemDetails,
+};
+
+/// A business error as an HTTP response
+///
+/// The status comes from `#[bizhttp(...)]` or the process-wide
+/// [`ProblemConfig`] (see [`set_problem_config`](crate::set_problem_config)),
+/// and the body is the [`ProblemDetails`] as `application/problem+json`.
+///
+/// The web framework traits can't be implemented for `ContextualError`
+/// directly, so handlers return `BizResponse` as their error type; `?`
+/// converts `ContextualError<E>` and `BizErrors<E>`. The `axum` feature
+/// implements `IntoResponse` and the `actix` feature `ResponseError`.
+///
+/// # Example
+///
+/// ```rust
+/// use bizerror::*;
+///
+/// #[derive(BizError, thiserror::Error)]
+/// pub enum UserError {
+///     #[bizcode(4004)]
+///     #[bizhttp(404)]
+///     #[error("User not found")]
+///     NotFound,
+/// }
+///
+/// fn find_user(id: u64) -> Result<String, ContextualError<UserError>> {
+///     Err(UserError::NotFound.with_context(format!("Finding user {id}")))
+/// }
+///
+/// fn handler() -> Result<String, BizResponse> {
+///     Ok(find_user(7)?)
+/// }
+///
+/// let response = handler().unwrap_err();
+/// assert_eq!(response.status(), 404);
+/// assert_eq!(response.problem().title(), "NotFound");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BizResponse {
+    problem: ProblemDetails,
+}
+
+impl BizResponse {
+    /// Create a response from problem details
+    pub const fn new(problem: ProblemDetails) -> Self {
+        Self { problem }
+    }
+
+    /// Create a response for a business error without context
+    pub fn from_biz_error<E: BizError>(error: &E) -> Self {
+        Self::new(ProblemConfig::current().biz_problem(error))
+    }
+
+    /// Get the HTTP status code
+    pub const fn status(&self) -> u16 {
+        self.problem.status()
+    }
+
+    /// Get the problem details
+    pub const fn problem(&self) -> &ProblemDetails {
+        &self.problem
+    }
+
+    /// Get the problem details, consuming the response
+    pub fn into_problem(self) -> ProblemDetails {
+        self.problem
+    }
+
+    /// Serialize the body as `application/problem+json`
+    pub fn body(&self) -> Vec<u8> {
+        serde_json::to_vec(&self.problem)
+            .expect("problem details always serialize to JSON")
+    }
+}
+
+impl fmt::Display for BizResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.problem.title(), self.problem.detail())
+    }
+}
+
+impl From<ProblemDetails> for BizResponse {
+    fn from(problem: ProblemDetails) -> Self {
+        Self::new(problem)
+    }
+}
+
+impl<E: BizError> From<ContextualError<E>> for BizResponse {
+    fn from(error: ContextualError<E>) -> Self {
+        Self::new(ProblemDetails::from(&error))
+    }
+}
+
+impl<E: BizError> From<BizErrors<E>> for BizResponse {
+    fn from(errors: BizErrors<E>) -> Self {
+        Self::new(ProblemDetails::from(&errors))
+    }
+}
+
+#[cfg(feature = "axum")]
+impl axum::response::IntoResponse for BizResponse {
+    fn into_response(self) -> axum::response::Response {
+        use axum::http::{
+            StatusCode,
+            header,
+        };
+
+        let status = StatusCode::from_u16(self.status())
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        (
+            status,
+            [(header::CONTENT_TYPE, ProblemDetails::CONTENT_TYPE)],
+            self.body(),
+        )
+            .into_response()
+    }
+}
+
+#[cfg(feature = "actix")]
+impl actix_web::ResponseError for BizResponse {
+    fn status_code(&self) -> actix_web::http::StatusCode {
+        actix_web::http::StatusCode::from_u16(self.status())
+            .unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> actix_web::HttpResponse {
+        actix_web::HttpResponse::build(self.status_code())
+            .content_type(ProblemDetails::CONTENT_TYPE)
+            .body(self.body())
+    }
+}
@@ -0,0 +1,222 @@
+//! A tower middleware turning business errors into HTTP responses, behind the
+//! `tower` feature
+
+use alloc::{
+    boxed::Box,
+    string::{
+        String,
+        ToString,
+    },
+    vec::Vec,
+};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{
+        Context,
+        Poll,
+    },
+};
+
+use ::http::{
+    HeaderValue,
+    Response,
+    StatusCode,
+    header,
+};
+use tower::{
+    Layer,
+    Service,
+};
+
+use crate::{
+    BizCode,
+    BizResponse,
+    DynBizError,
+    ProblemConfig,
+    ProblemDetails,
+};
+
+/// A [`Layer`] converting the business errors of a service into
+/// `application/problem+json` responses
+///
+/// The inner service returns `http::Response<B>` and fails with any
+/// [`BizError`](crate::BizError) or a `Box<dyn DynBizError>`. A failure
+/// becomes a response with the status of `#[bizhttp(...)]` or the
+/// [`ProblemConfig`], the [`ProblemDetails`] as body and a [`BizErrorInfo`]
+/// in the response extensions, for logging further out. Successful responses
+/// pass through unchanged. A [`ContextualError`](crate::ContextualError)
+/// keeps its context frames through the boxing.
+///
+/// # Example
+///
+/// ```rust
+/// use bizerror::*;
+/// use tower::{
+///     Layer,
+///     ServiceExt,
+///     service_fn,
+/// };
+///
+/// #[derive(BizError, thiserror::Error)]
+/// pub enum UserError {
+///     #[bizcode(4004)]
+///     #[bizhttp(404)]
+///     #[error("User not found")]
+///     NotFound,
+/// }
+///
+/// let service = BizErrorLayer::new().layer(service_fn(
+///     |_request: http::Request<()>| async {
+///         Err::<http::Response<Vec<u8>>, _>(UserError::NotFound)
+///     },
+/// ));
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread()
+///     .build()
+///     .unwrap();
+/// let response = runtime
+///     .block_on(service.oneshot(http::Request::new(())))
+///     .unwrap();
+/// assert_eq!(response.status(), 404);
+///
+/// let info = response.extensions().get::<BizErrorInfo>().unwrap();
+/// assert_eq!(info.code(), &BizCode::Int(4004));
+/// assert_eq!(info.name(), "NotFound");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BizErrorLayer {
+    config: Option<ProblemConfig>,
+}
+
+impl BizErrorLayer {
+    /// Create a layer using the process-wide [`ProblemConfig`]
+    pub const fn new() -> Self {
+        Self { config: None }
+    }
+
+    /// Use `config` instead of the process-wide configuration
+    #[must_use]
+    pub const fn config(mut self, config: ProblemConfig) -> Self {
+        self.config = Some(config);
+        self
+    }
+}
+
+impl<S> Layer<S> for BizErrorLayer {
+    type Service = BizErrorService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        BizErrorService {
+            inner,
+            config: self.config,
+        }
+    }
+}
+
+/// The service built by [`BizErrorLayer`]
+#[derive(Debug, Clone)]
+pub struct BizErrorService<S> {
+    inner:  S,
+    config: Option<ProblemConfig>,
+}
+
+impl<S, Request, B> Service<Request> for BizErrorService<S>
+where
+    S: Service<Request, Response = Response<B>>,
+    S::Error: Into<Box<dyn DynBizError>>,
+    B: From<Vec<u8>>,
+{
+    type Response = Response<B>;
+    // Only readiness errors of the inner service are left
+    type Error = S::Error;
+    type Future = ResponseFuture<S::Future>;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), S::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        ResponseFuture {
+            inner:  Box::pin(self.inner.call(request)),
+            config: self.config,
+        }
+    }
+}
+
+/// The response future of [`BizErrorService`]
+pub struct ResponseFuture<F> {
+    inner:  Pin<Box<F>>,
+    config: Option<ProblemConfig>,
+}
+
+impl<F, B, E> Future for ResponseFuture<F>
+where
+    F: Future<Output = Result<Response<B>, E>>,
+    E: Into<Box<dyn DynBizError>>,
+    B: From<Vec<u8>>,
+{
+    type Output = Result<Response<B>, E>;
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Self::Output> {
+        let error = match self.inner.as_mut().poll(cx) {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(Ok(response)) => return Poll::Ready(Ok(response)),
+            Poll::Ready(Err(error)) => error.into(),
+        };
+        let config = self.config.unwrap_or_else(ProblemConfig::current);
+        Poll::Ready(Ok(error_response(&config, &*error)))
+    }
+}
+
+fn error_response<B: From<Vec<u8>>>(
+    config: &ProblemConfig,
+    error: &dyn DynBizError,
+) -> Response<B> {
+    let response = BizResponse::new(config.problem(error));
+    let mut http_response = Response::new(B::from(response.body()));
+    *http_response.status_mut() = StatusCode::from_u16(response.status())
+        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    http_response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static(ProblemDetails::CONTENT_TYPE),
+    );
+    http_response.extensions_mut().insert(BizErrorInfo {
+        code:   error.code(),
+        name:   error.name().to_string(),
+        status: response.status(),
+    });
+    http_response
+}
+
+/// The business error behind a response built by [`BizErrorLayer`], found in
+/// the response extensions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BizErrorInfo {
+    code:   BizCode,
+    name:   String,
+    status: u16,
+}
+
+impl BizErrorInfo {
+    /// Get the business error code
+    pub const fn code(&self) -> &BizCode {
+        &self.code
+    }
+
+    /// Get the business error name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the HTTP status of the response
+    pub const fn status(&self) -> u16 {
+        self.status
+    }
+}
@@ -70,6 +70,58 @@
 //! }
 //! ```
 //!
+//! Settings shared by every enum of a crate or workspace can live in
+//! `Cargo.toml` instead of being repeated in each `#[bizconfig]`. Package
+//! settings override workspace settings, and `#[bizconfig]` overrides both:
+//!
+//! ```toml
+//! [workspace.metadata.bizerror]
+//! code_type = "u32"
+//! auto_increment = 10
+//! doc_url = "https://errors.example.com/{code}"
+//!
+//! [package.metadata.bizerror]
+//! auto_start = 3000
+//! name_case = "snake_case"
+//! severity = "warning"
+//! ```
+//!
+//! Besides the code settings, `name_case` sets the case of
+//! [`name()`](BizError::name) (`PascalCase`, `camelCase`, `snake_case`,
+//! `SCREAMING_SNAKE_CASE` or `kebab-case`), `doc_url` is the template of
+//! [`doc_url()`](BizError::doc_url) and `severity` the default
+//! [`Severity`], which `#[bizseverity(...)]` overrides per variant.
+//!
+//! ## 🔢 Code Constants
+//!
+//! For numeric and `&'static str` code types, every variant's code is also
+//! available as an associated constant, so codes received from elsewhere can
+//! be matched directly:
+//!
+//! ```rust
+//! use bizerror::BizError;
+//!
+//! #[derive(BizError, thiserror::Error)]
+//! pub enum ApiError {
+//!     #[bizcode(4001)]
+//!     #[error("Invalid input")]
+//!     ValidationError,
+//!
+//!     #[bizcode(8006)]
+//!     #[error("Request timeout")]
+//!     Timeout,
+//! }
+//!
+//! const TIMEOUT: u32 = ApiError::Timeout.code_of_kind();
+//!
+//! let retryable = |code: u32| match code {
+//!     ApiError::TIMEOUT_CODE => true,
+//!     ApiError::VALIDATION_ERROR_CODE => false,
+//!     _ => false,
+//! };
+//! assert!(retryable(TIMEOUT));
+//! ```
+//!
 //! ## 🔧 Advanced Usage with Context
 //!
 //! For scenarios requiring detailed context information:
@@ -101,6 +153,44 @@
 //! # }
 //! ```
 //!
+//! ## 🔀 Cross-Enum Conversions
+//!
+//! `#[bizmap]` generates a `From` impl that maps variants of another error
+//! enum onto specific variants of this one, keeping the original error as the
+//! source. Each target variant holds the source error in its only field (use
+//! `#[source]` rather than `#[from]` to avoid a conflicting `From` impl):
+//!
+//! ```rust
+//! use bizerror::*;
+//!
+//! #[derive(BizError, thiserror::Error)]
+//! pub enum UserServiceError {
+//!     #[bizcode(1001)]
+//!     #[error("User not found")]
+//!     UserNotFound,
+//!
+//!     #[bizcode(1002)]
+//!     #[error("Password too weak")]
+//!     WeakPassword,
+//! }
+//!
+//! #[derive(BizError, thiserror::Error)]
+//! #[bizmap(from = UserServiceError, UserNotFound => NotFound, _ => Internal)]
+//! pub enum AppError {
+//!     #[bizcode(404)]
+//!     #[error("Not found")]
+//!     NotFound(#[source] UserServiceError),
+//!
+//!     #[bizcode(500)]
+//!     #[error("Internal error")]
+//!     Internal(#[source] UserServiceError),
+//! }
+//!
+//! let error = AppError::from(UserServiceError::UserNotFound);
+//! assert_eq!(error.code(), 404);
+//! assert_eq!(AppError::from(UserServiceError::WeakPassword).code(), 500);
+//! ```
+//!
 //! ## 📊 Custom Code Types
 //!
 //! You can use different types for error codes:
@@ -146,6 +236,36 @@
 //! // Output: ApiError { variant: "ValidationError", code: 4001, message: "Invalid input: email" }
 //! ```
 //!
+//! ## 🔒 Sensitive Fields
+//!
+//! Mark fields carrying secrets with `#[biz(sensitive)]`: they are reported as
+//! `<redacted>` by [`BizError::fields`], and the derived `Debug` hides the
+//! message of the variant. Wrap the field type in [`Redacted`] to keep the
+//! value out of the `Display` message as well:
+//!
+//! ```rust
+//! use bizerror::*;
+//!
+//! #[derive(BizError, thiserror::Error)]
+//! pub enum PaymentError {
+//!     #[bizcode(4020)]
+//!     #[error("Card {card_number} declined")]
+//!     CardDeclined {
+//!         #[biz(sensitive)]
+//!         card_number: String,
+//!     },
+//! }
+//!
+//! let error = PaymentError::CardDeclined {
+//!     card_number: "4111111111111111".to_string(),
+//! };
+//! assert!(!format!("{error:?}").contains("4111"));
+//! assert_eq!(error.fields(), vec![(
+//!     "card_number".into(),
+//!     BizFieldValue::Redacted
+//! )]);
+//! ```
+//!
 //! ## 🔗 Error Chains and Context
 //!
 //! Build comprehensive error chains with context:
@@ -171,6 +291,92 @@
 //! }
 //! ```
 //!
+//! ## 📦 `no_std` Support
+//!
+//! `BizError` and the derive macro only need `core`: the trait builds on
+//! [`core::error::Error`], and location tracking relies on
+//! [`core::panic::Location`]. Heap-backed pieces are behind features:
+//!
+//! - `alloc`: [`ContextualError`], [`BizErrors`], the context extension traits
+//!   and [`BizError::fields`]
+//! - `std` (default): implies `alloc`
+//! - `backtrace`: capture a `std::backtrace::Backtrace` in every
+//!   [`ContextualError`], filtered by an optional `BacktracePolicy`; implies
+//!   `std`
+//! - `defmt`: `defmt::Format` for the allocation-free types below
+//! - `serde`: `serde::Serialize` for [`ContextualError`] and [`BizErrors`], and
+//!   `#[bizconfig(serde)]` to derive it for error enums; implies `alloc`
+//!
+//! Without `alloc`, [`StaticContextualError`] attaches `&'static str` context
+//! and a location, and [`BizErrorsArray`] aggregates up to `N` errors inline,
+//! counting any that did not fit.
+//!
+//! ```toml
+//! [dependencies]
+//! bizerror = { version = "0.1", default-features = false, features = ["alloc"] }
+//! ```
+//!
+//! ## 🧾 Serialization
+//!
+//! With the `serde` feature, [`ContextualError`] serializes as
+//! `{code, name, message, fields, context, location, sources}` and
+//! [`BizErrors`] as `{count, errors}`. `#[bizconfig(serde)]` implements
+//! `Serialize` for an error enum as `{code, name, message, fields}`, and
+//! [`FromBizCode`] for its unit variants. Sensitive errors add
+//! `sensitive: true`. On the receiving side,
+//! `RemoteBizError` deserializes either form and maps back to a local
+//! variant with `try_into_typed`:
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")]
+//! # fn main() {
+//! use bizerror::*;
+//!
+//! #[derive(BizError, thiserror::Error)]
+//! #[bizconfig(serde)]
+//! pub enum ApiError {
+//!     #[bizcode(4001)]
+//!     #[error("Invalid input: {field}")]
+//!     ValidationError { field: String },
+//! }
+//!
+//! let error = ApiError::ValidationError {
+//!     field: "email".to_string(),
+//! };
+//! assert_eq!(
+//!     serde_json::to_string(&error).unwrap(),
+//!     r#"{"code":4001,"name":"ValidationError","message":"Invalid input: email","fields":{"field":"email"}}"#
+//! );
+//! # }
+//! # #[cfg(not(feature = "serde"))]
+//! # fn main() {}
+//! ```
+//!
+//! ## 🌐 Problem Details
+//!
+//! [`ProblemConfig`] turns business errors into RFC 9457 [`ProblemDetails`]:
+//! the code fills a `type` URI template, the name becomes the `title`, and
+//! the status comes from `#[bizhttp(...)]`, code ranges (`4xxx` codes are
+//! `400` by default), a fixed value or a mapping function. Context and
+//! sources are included unless the configuration is in
+//! [`production`](ProblemConfig::production) mode. With `serde`, problem
+//! details serialize to `application/problem+json`.
+//!
+//! ## 📡 gRPC
+//!
+//! `#[bizgrpc(not_found)]` sets the [`GrpcCode`] of a variant; other variants
+//! follow their HTTP status. With the `tonic` feature, `?` converts a
+//! [`ContextualError`] into a `tonic::Status` whose `google.rpc.ErrorInfo`
+//! detail holds the business name as `reason` and the code in `metadata` as
+//! `bizerror_code`. Clients get it back with
+//! `RemoteBizError::from_grpc_status`.
+//!
+//! ## 🗼 Tower Middleware
+//!
+//! With the `tower` feature, `BizErrorLayer` wraps any `tower::Service`
+//! failing with a business error and answers with the problem details
+//! instead, recording the code and name in the response extensions.
+//!
 //! ## 🏆 Best Practices
 //!
 //! 1. **Use meaningful error codes**: Group related errors by code ranges
@@ -187,15 +393,131 @@
 //! 4. **Chain errors properly**: Use `#[from]` for automatic conversions
 //!
 //! 5. **Document error codes**: Include code meanings in your API documentation
-
-use core::panic::Location;
-use std::{
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "backtrace")]
+mod backtrace;
+#[cfg(feature = "alloc")]
+mod chain;
+#[cfg(feature = "alloc")]
+mod collection;
+#[cfg(feature = "alloc")]
+mod contextual;
+#[cfg(feature = "alloc")]
+mod dynamic;
+#[cfg(feature = "alloc")]
+mod fields;
+mod fixed;
+mod grpc;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(feature = "tower")]
+mod layer;
+#[cfg(feature = "alloc")]
+mod problem;
+#[cfg(all(feature = "serde", feature = "std"))]
+mod remote;
+#[cfg(feature = "alloc")]
+mod report;
+#[cfg(feature = "serde")]
+mod serialize;
+mod severity;
+
+#[cfg(feature = "alloc")]
+use alloc::{
     borrow::Cow,
-    error::Error,
+    vec::Vec,
 };
+use core::error::Error;
 
+#[cfg(feature = "backtrace")]
+pub use backtrace::{
+    BacktracePolicy,
+    BacktraceRequest,
+    set_backtrace_policy,
+};
 // Re-export the BizError derive macro
 pub use bizerror_impl::BizError;
+#[cfg(feature = "alloc")]
+pub use chain::{
+    Chain,
+    ChainLink,
+};
+#[cfg(feature = "std")]
+pub use chain::{
+    ErrorChainExt,
+    as_biz_error,
+    register_biz_error,
+};
+#[cfg(feature = "alloc")]
+pub use collection::BizErrors;
+#[cfg(feature = "alloc")]
+pub use contextual::{
+    BizErrorExt,
+    ContextFrame,
+    ContextualError,
+    IntoContextualError,
+    ResultExt,
+};
+#[cfg(feature = "alloc")]
+pub use dynamic::{
+    BizCode,
+    DynBizError,
+    FromBizCode,
+};
+#[cfg(feature = "alloc")]
+pub use fields::{
+    BizFieldValue,
+    ToBizFieldValue,
+};
+pub use fixed::{
+    BizErrorsArray,
+    StaticContextualError,
+};
+pub use grpc::GrpcCode;
+#[cfg(feature = "tonic")]
+pub use grpc::grpc_status;
+#[cfg(feature = "http")]
+pub use http::BizResponse;
+#[cfg(feature = "tower")]
+pub use layer::{
+    BizErrorInfo,
+    BizErrorLayer,
+    BizErrorService,
+    ResponseFuture,
+};
+#[cfg(feature = "std")]
+pub use problem::set_problem_config;
+#[cfg(feature = "alloc")]
+pub use problem::{
+    ProblemConfig,
+    ProblemDetails,
+    StatusMapper,
+    StatusRanges,
+};
+#[cfg(all(feature = "serde", feature = "std"))]
+pub use remote::{
+    RemoteBizError,
+    RemoteCause,
+    RemoteCode,
+    RemoteFrame,
+};
+#[cfg(feature = "alloc")]
+pub use report::{
+    Compact,
+    Logfmt,
+    Report,
+    ReportEntry,
+    ReportStyle,
+    Reportable,
+    Tree,
+};
+pub use severity::Severity;
 
 /// Core business error trait
 ///
@@ -219,8 +541,8 @@ pub use bizerror_impl::BizError;
 ///     message: String,
 /// }
 ///
-/// impl std::fmt::Display for CustomError {
-///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// impl core::fmt::Display for CustomError {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 ///         write!(f, "{}", self.message)
 ///     }
 /// }
@@ -242,17 +564,17 @@ pub use bizerror_impl::BizError;
 pub trait BizError: Error + Send + Sync + 'static {
     /// The type of the error code
     ///
-    /// Can be any type that implements `Copy + Display + Debug + Send + Sync +
-    /// 'static`. Common choices include:
+    /// Can be any `'static` type that is `Clone`, `Display`, `Debug`, `Send`,
+    /// `Sync`, `Hash` and `Eq`. Common choices include:
     /// - `u32` or `u16` for numeric codes
     /// - `&'static str` for string codes
     /// - `i32` for signed numeric codes
-    type CodeType: Copy
-        + std::fmt::Display
-        + std::fmt::Debug
+    type CodeType: Clone
+        + core::fmt::Display
+        + core::fmt::Debug
         + Send
         + Sync
-        + std::hash::Hash
+        + core::hash::Hash
         + PartialEq
         + Eq
         + 'static;
@@ -270,83 +592,18 @@ pub trait BizError: Error + Send + Sync + 'static {
     /// implementations. For custom implementations, this should return a
     /// consistent, descriptive name.
     fn name(&self) -> &str;
-}
-
-/// Contextual error wrapper (only used when detailed context is needed)
-///
-/// This wrapper allows you to add context information and automatic location
-/// tracking to any `BizError` without changing the original error type.
-///
-/// ## When to Use
-///
-/// Use `ContextualError` when you need:
-/// - Detailed debugging information
-/// - Location tracking for where the error occurred
-/// - Additional context about the operation that failed
-/// - Multiple layers of context in error chains
-///
-/// ## Example
-///
-/// ```rust
-/// use bizerror::*;
-///
-/// #[derive(BizError, thiserror::Error)]
-/// pub enum ServiceError {
-///     #[bizcode(8001)]
-///     #[error("Database connection failed")]
-///     DatabaseError(#[from] std::io::Error),
-/// }
-///
-/// fn load_config() -> Result<String, ContextualError<ServiceError>> {
-///     std::fs::read_to_string("config.json")
-///         .with_context("Loading application configuration")
-/// }
-/// ```
-pub struct ContextualError<E: BizError> {
-    error:    E,
-    context:  Cow<'static, str>, // Avoids allocation for static strings,
-    location: &'static Location<'static>,
-}
-
-impl<E: BizError> ContextualError<E> {
-    /// Create a new contextual error with automatic location tracking
-    ///
-    /// The location is automatically captured using `#[track_caller]`,
-    /// providing precise information about where the error context was added.
-    #[track_caller]
-    pub fn new(error: E, context: impl Into<String>) -> Self {
-        Self {
-            error,
-            context: Cow::Owned(context.into()),
-            location: Location::caller(),
-        }
-    }
-
-    /// Get the original error
-    ///
-    /// This provides access to the underlying `BizError` instance.
-    pub const fn inner(&self) -> &E {
-        &self.error
-    }
 
-    /// Get the context
+    /// Get the structured fields carried by this error
     ///
-    /// Returns the contextual information that was added to this error.
-    pub fn context(&self) -> &str {
-        &self.context
-    }
-
-    /// Get the location
+    /// Returns the error's data as key/value pairs, suitable for structured
+    /// logging and serialization without parsing the `Display` output.
+    /// Derived implementations expose every named and tuple field whose type
+    /// implements [`ToBizFieldValue`], skipping source fields (`#[from]`,
+    /// `#[source]` or a field named `source`). Tuple fields are keyed by their
+    /// index (`"0"`, `"1"`, ...). Fields marked `#[biz(sensitive)]` are
+    /// reported as [`BizFieldValue::Redacted`].
     ///
-    /// Returns the location where the context was added to this error.
-    pub const fn location(&self) -> &'static Location<'static> {
-        self.location
-    }
-
-    /// Add additional context to the existing context
-    ///
-    /// This method appends new context information to the existing context,
-    /// creating a layered context description.
+    /// The default implementation returns no fields.
     ///
     /// # Example
     ///
@@ -354,237 +611,185 @@ impl<E: BizError> ContextualError<E> {
     /// use bizerror::*;
     ///
     /// #[derive(BizError, thiserror::Error)]
-    /// pub enum MyError {
-    ///     #[bizcode(8001)]
-    ///     #[error("IO error")]
-    ///     IoError,
+    /// pub enum ApiError {
+    ///     #[bizcode(4001)]
+    ///     #[error("Invalid input: {field}")]
+    ///     ValidationError { field: String, attempts: u32 },
     /// }
     ///
-    /// let error = MyError::IoError;
-    /// let contextual = error.with_context("Loading file");
-    /// let layered = contextual.add_context("During startup");
-    /// assert_eq!(layered.context(), "Loading file -> During startup");
+    /// let error = ApiError::ValidationError {
+    ///     field:    "email".to_string(),
+    ///     attempts: 3,
+    /// };
+    /// assert_eq!(error.fields(), vec![
+    ///     ("field".into(), BizFieldValue::Str("email".to_string())),
+    ///     ("attempts".into(), BizFieldValue::UInt(3)),
+    /// ]);
     /// ```
-    #[track_caller]
-    #[must_use]
-    pub fn add_context(self, additional: impl Into<String>) -> Self {
-        let new_context = format!("{} -> {}", self.context, additional.into());
-        Self {
-            error:    self.error,
-            context:  Cow::Owned(new_context),
-            location: Location::caller(),
-        }
+    #[cfg(feature = "alloc")]
+    fn fields(&self) -> Vec<(Cow<'static, str>, BizFieldValue)> {
+        Vec::new()
     }
 
-    /// Unwrap the contextual error, returning the inner error
+    /// Whether this error carries sensitive data
     ///
-    /// This method consumes the `ContextualError` and returns the underlying
-    /// business error, discarding the context information.
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// use bizerror::*;
+    /// When `true`, the error message is hidden from `Debug` output, since it
+    /// may embed the sensitive values. Derived implementations return `true`
+    /// for variants with `#[biz(sensitive)]` fields.
     ///
-    /// #[derive(BizError, thiserror::Error)]
-    /// pub enum MyError {
-    ///     #[bizcode(8001)]
-    ///     #[error("IO error")]
-    ///     IoError,
-    /// }
-    ///
-    /// let error = MyError::IoError;
-    /// let contextual = error.with_context("Some context");
-    /// let original = contextual.into_inner();
-    /// // original is now MyError::IoError again
-    /// ```
-    pub fn into_inner(self) -> E {
-        self.error
+    /// The default implementation returns `false`.
+    fn is_sensitive(&self) -> bool {
+        false
     }
 
-    /// Find the first error in the chain of a specific type
+    /// Get the long-form description of this error
     ///
-    /// This method traverses the error chain and returns the first error
-    /// of the specified type. Useful for extracting specific error types
-    /// from a complex error chain.
+    /// Derived implementations return the variant's `///` doc comment, so the
+    /// documentation of when an error occurs doubles as runtime "explain"
+    /// output.
     ///
-    /// # Example
-    ///
-    /// ```rust
-    /// use std::io;
-    ///
-    /// use bizerror::*;
-    ///
-    /// #[derive(BizError, thiserror::Error)]
-    /// pub enum MyError {
-    ///     #[bizcode(8001)]
-    ///     #[error("IO error: {0}")]
-    ///     IoError(#[from] io::Error),
-    /// }
-    ///
-    /// let io_error = io::Error::new(io::ErrorKind::NotFound, "file not found");
-    /// let my_error = MyError::IoError(io_error);
-    /// let contextual = my_error.with_context("Loading config");
-    ///
-    /// // Find the original io::Error in the chain
-    /// let found_io_error = contextual.find_root::<io::Error>();
-    /// assert!(found_io_error.is_some());
-    /// ```
-    pub fn find_root<T>(&self) -> Option<&T>
-    where
-        T: Error + 'static,
-    {
-        let mut current: &dyn Error = self;
-        while let Some(source) = current.source() {
-            if let Some(target) = source.downcast_ref::<T>() {
-                return Some(target);
-            }
-            current = source;
-        }
-        None
-    }
-
-    /// Count the depth of the error chain
+    /// When `std::error::Error` is imported as well, call it as
+    /// `BizError::description(&error)` to avoid ambiguity with the deprecated
+    /// `Error::description`.
     ///
-    /// Returns the number of errors in the chain, including this error.
-    /// Useful for understanding the complexity of error propagation.
+    /// The default implementation returns `None`.
     ///
     /// # Example
     ///
     /// ```rust
     /// use bizerror::*;
-    /// use std::io;
     ///
     /// #[derive(BizError, thiserror::Error)]
-    /// pub enum MyError {
-    ///     #[bizcode(8001)]
-    ///     #[error("IO error: {0}")]
-    ///     IoError(#[from] io::Error),
+    /// pub enum ApiError {
+    ///     /// The request rate exceeded the quota of the API key.
+    ///     ///
+    ///     /// Retry after the period given in the `Retry-After` header.
+    ///     #[bizcode(4290)]
+    ///     #[error("Rate limited")]
+    ///     RateLimited,
+    ///
+    ///     #[bizcode(5000)]
+    ///     #[error("Internal error")]
+    ///     Internal,
     /// }
     ///
-    /// let io_error = io::Error::new(io::ErrorKind::NotFound, "file not found");
-    /// let my_error = MyError::IoError(io_error);
-    /// let contextual = my_error.with_context("Loading config");
-    ///
-    /// assert_eq!(contextual.chain_depth(), 3); // ContextualError -> MyError -> io::Error
+    /// assert_eq!(
+    ///     ApiError::RateLimited.description(),
+    ///     Some(
+    ///         "The request rate exceeded the quota of the API key.\n\nRetry \
+    ///          after the period given in the `Retry-After` header."
+    ///     )
+    /// );
+    /// assert_eq!(ApiError::Internal.description(), None);
     /// ```
-    pub fn chain_depth(&self) -> usize {
-        let mut depth = 1;
-        let mut current: &dyn Error = self;
-        while let Some(source) = current.source() {
-            depth += 1;
-            current = source;
-        }
-        depth
+    fn description(&self) -> Option<&'static str> {
+        None
     }
 
-    /// Get the root cause message of the error chain
+    /// Get the HTTP status of this error, if the variant sets one
     ///
-    /// Returns the deepest error message in the chain, which is typically the
-    /// original cause of the error.
+    /// Derived implementations return the status of `#[bizhttp(...)]`.
+    /// Variants without it are mapped by the [`ProblemConfig`] in use, e.g.
+    /// by code range.
+    ///
+    /// The default implementation returns `None`.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use std::io;
-    ///
     /// use bizerror::*;
     ///
     /// #[derive(BizError, thiserror::Error)]
-    /// pub enum MyError {
-    ///     #[bizcode(8001)]
-    ///     #[error("IO error: {0}")]
-    ///     IoError(#[from] io::Error),
-    /// }
+    /// pub enum ApiError {
+    ///     #[bizcode(4004)]
+    ///     #[bizhttp(404)]
+    ///     #[error("User not found")]
+    ///     UserNotFound,
     ///
-    /// let io_error = io::Error::new(io::ErrorKind::NotFound, "file not found");
-    /// let my_error = MyError::IoError(io_error);
-    /// let contextual = my_error.with_context("Loading config");
+    ///     #[bizcode(5000)]
+    ///     #[error("Internal error")]
+    ///     Internal,
+    /// }
     ///
-    /// let root_cause = contextual.root_cause_message();
-    /// assert_eq!(root_cause, "file not found");
+    /// assert_eq!(ApiError::UserNotFound.http_status(), Some(404));
+    /// assert_eq!(ApiError::Internal.http_status(), None);
     /// ```
-    pub fn root_cause_message(&self) -> String {
-        let mut current: &dyn Error = self;
-        while let Some(source) = current.source() {
-            current = source;
-        }
-        current.to_string()
+    fn http_status(&self) -> Option<u16> {
+        None
     }
 
-    /// Collect all error messages in the chain
+    /// Get the gRPC status code of this error, if the variant sets one
+    ///
+    /// Derived implementations return the code of `#[bizgrpc(...)]`, written
+    /// in snake case like `not_found`. Without it, the `tonic` conversions
+    /// derive the code from the HTTP status.
     ///
-    /// Returns a vector of all error messages in the chain, from the current
-    /// error to the root cause. Useful for comprehensive error reporting.
+    /// The default implementation returns `None`.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use std::io;
-    ///
     /// use bizerror::*;
     ///
     /// #[derive(BizError, thiserror::Error)]
-    /// pub enum MyError {
-    ///     #[bizcode(8001)]
-    ///     #[error("IO error: {0}")]
-    ///     IoError(#[from] io::Error),
-    /// }
+    /// pub enum ApiError {
+    ///     #[bizcode(4004)]
+    ///     #[bizgrpc(not_found)]
+    ///     #[error("User not found")]
+    ///     UserNotFound,
     ///
-    /// let io_error = io::Error::new(io::ErrorKind::NotFound, "file not found");
-    /// let my_error = MyError::IoError(io_error);
-    /// let contextual = my_error.with_context("Loading config");
+    ///     #[bizcode(5000)]
+    ///     #[error("Internal error")]
+    ///     Internal,
+    /// }
     ///
-    /// let chain = contextual.error_chain_messages();
-    /// assert_eq!(chain.len(), 3);
+    /// assert_eq!(ApiError::UserNotFound.grpc_code(), Some(GrpcCode::NotFound));
+    /// assert_eq!(ApiError::Internal.grpc_code(), None);
     /// ```
-    pub fn error_chain_messages(&self) -> Vec<String> {
-        let mut chain = vec![self.to_string()];
-        let mut current = self.source();
-        while let Some(source) = current {
-            chain.push(source.to_string());
-            current = source.source();
-        }
-        chain
+    fn grpc_code(&self) -> Option<GrpcCode> {
+        None
     }
 
-    /// Check if the error chain contains a specific error type
+    /// Get the severity of this error
+    ///
+    /// Derived implementations return the severity of
+    /// `#[bizseverity(...)]`, falling back to the `severity` of
+    /// `#[bizconfig]` or the `Cargo.toml` metadata.
     ///
-    /// Returns true if any error in the chain is of the specified type.
-    /// Useful for conditional error handling.
+    /// The default implementation returns [`Severity::Error`].
     ///
     /// # Example
     ///
     /// ```rust
-    /// use std::io;
-    ///
     /// use bizerror::*;
     ///
     /// #[derive(BizError, thiserror::Error)]
-    /// pub enum MyError {
-    ///     #[bizcode(8001)]
-    ///     #[error("IO error: {0}")]
-    ///     IoError(#[from] io::Error),
+    /// pub enum ApiError {
+    ///     #[bizcode(4290)]
+    ///     #[bizseverity(info)]
+    ///     #[error("Rate limited")]
+    ///     RateLimited,
+    ///
+    ///     #[bizcode(5000)]
+    ///     #[error("Internal error")]
+    ///     Internal,
     /// }
     ///
-    /// let io_error = io::Error::new(io::ErrorKind::NotFound, "file not found");
-    /// let my_error = MyError::IoError(io_error);
-    /// let contextual = my_error.with_context("Loading config");
-    ///
-    /// assert!(contextual.contains_error::<io::Error>());
-    /// assert!(!contextual.contains_error::<std::fmt::Error>());
+    /// assert_eq!(ApiError::RateLimited.severity(), Severity::Info);
+    /// assert_eq!(ApiError::Internal.severity(), Severity::Error);
     /// ```
-    pub fn contains_error<T>(&self) -> bool
-    where
-        T: Error + 'static,
-    {
-        self.find_root::<T>().is_some()
+    fn severity(&self) -> Severity {
+        Severity::Error
     }
 
-    /// Check if the error chain contains a specific business error code
+    /// Get the URL documenting this error
     ///
-    /// Returns true if any `BizError` in the chain has the specified code.
-    /// Useful for conditional error handling based on business error codes.
+    /// Derived implementations fill the `doc_url` template of `#[bizconfig]`
+    /// or the `Cargo.toml` metadata, replacing `{code}` with the code and
+    /// `{name}` with the name. [`ProblemConfig`] uses it as the problem
+    /// `type` when no type template is configured.
+    ///
+    /// The default implementation returns `None`.
     ///
     /// # Example
     ///
@@ -592,269 +797,102 @@ impl<E: BizError> ContextualError<E> {
     /// use bizerror::*;
     ///
     /// #[derive(BizError, thiserror::Error)]
-    /// pub enum MyError {
-    ///     #[bizcode(8001)]
-    ///     #[error("IO error")]
-    ///     IoError,
-    ///
-    ///     #[bizcode(8002)]
-    ///     #[error("Network error")]
-    ///     NetworkError,
+    /// #[bizconfig(doc_url = "https://errors.example.com/{code}")]
+    /// pub enum ApiError {
+    ///     #[bizcode(4004)]
+    ///     #[error("User not found")]
+    ///     UserNotFound,
     /// }
     ///
-    /// let error = MyError::IoError;
-    /// let contextual = error.with_context("Operation failed");
-    ///
-    /// assert!(contextual.chain_contains_code(8001));
-    /// assert!(!contextual.chain_contains_code(8002));
+    /// assert_eq!(
+    ///     ApiError::UserNotFound.doc_url(),
+    ///     Some("https://errors.example.com/4004")
+    /// );
     /// ```
-    pub fn chain_contains_code<C>(&self, code: C) -> bool
-    where
-        C: PartialEq<E::CodeType> + Copy,
-    {
-        let mut current: &dyn Error = self;
-        loop {
-            if let Some(biz_error) = current.downcast_ref::<E>() &&
-                code == biz_error.code()
-            {
-                return true;
-            }
-            if let Some(contextual) = current.downcast_ref::<Self>() &&
-                code == contextual.error.code()
-            {
-                return true;
-            }
-            if let Some(source) = current.source() {
-                current = source;
-            } else {
-                break;
-            }
-        }
-        false
-    }
-}
-
-impl<E: BizError> std::fmt::Debug for ContextualError<E> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("ContextualError")
-            .field("type", &self.error.name())
-            .field("code", &self.error.code())
-            .field("message", &self.error.to_string())
-            .field("context", &self.context.as_ref())
-            .field(
-                "location",
-                &format!(
-                    "{}:{}:{}",
-                    self.location.file(),
-                    self.location.line(),
-                    self.location.column()
-                ),
-            )
-            .finish()
-    }
-}
-
-impl<E: BizError> std::fmt::Display for ContextualError<E> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}\nContext: {}", self.error, self.context)
+    fn doc_url(&self) -> Option<&'static str> {
+        None
     }
-}
 
-impl<E: BizError> Error for ContextualError<E> {
-    fn source(&self) -> Option<&(dyn Error + 'static)> {
-        Some(&self.error)
+    /// Report entry with context frames, for [`ContextualError`] seen as
+    /// `dyn DynBizError`
+    #[cfg(feature = "alloc")]
+    #[doc(hidden)]
+    fn report_entry(&self) -> Option<ReportEntry<'_>> {
+        None
     }
 }
 
-impl<E: BizError> BizError for ContextualError<E> {
-    type CodeType = E::CodeType;
+pub(crate) const REDACTED: &str = "<redacted>";
 
-    fn code(&self) -> Self::CodeType {
-        self.error.code()
-    }
-
-    fn name(&self) -> &str {
-        self.error.name()
-    }
-}
-
-/// Result extension trait (simplified)
-///
-/// Provides convenient methods to add business context to any Result.
-/// This trait is automatically implemented for all `Result<T, E>` types
-/// where `E` implements `Error`.
-///
-/// ## Core Methods
+/// Wrapper that hides a sensitive value from `Display` and `Debug`
 ///
-/// - `with_context()` - Add context and convert to `ContextualError`
-/// - `map_biz()` - Simple error type conversion
-/// - `with_context_if()` - Conditional context addition
+/// Use it as the field type of variants carrying passwords, tokens or card
+/// numbers so the value can't leak through the error message, logs or
+/// structured fields. The value is only accessible through an explicit
+/// [`Redacted::reveal`].
 ///
-/// ## Example
+/// # Example
 ///
 /// ```rust
 /// use bizerror::*;
 ///
 /// #[derive(BizError, thiserror::Error)]
-/// pub enum MyError {
-///     #[bizcode(8001)]
-///     #[error("IO error: {0}")]
-///     IoError(#[from] std::io::Error),
+/// pub enum AuthError {
+///     #[bizcode(4010)]
+///     #[error("Invalid password for {user}: {password}")]
+///     InvalidPassword {
+///         user:     String,
+///         password: Redacted<String>,
+///     },
 /// }
 ///
-/// fn read_file() -> Result<String, ContextualError<MyError>> {
-///     std::fs::read_to_string("important.txt")
-///         .with_context("Reading critical configuration file")
+/// let error = AuthError::InvalidPassword {
+///     user:     "alice".to_string(),
+///     password: Redacted::new("hunter2".to_string()),
+/// };
+/// assert_eq!(error.to_string(), "Invalid password for alice: <redacted>");
+///
+/// if let AuthError::InvalidPassword { password, .. } = &error {
+///     assert_eq!(password.reveal(), "hunter2");
 /// }
 /// ```
-pub trait ResultExt<T, E> {
-    /// Add contextual information and convert to `ContextualError`
-    ///
-    /// This method allows you to add context to any `Result` that contains
-    /// an error that can be converted to your business error type.
-    ///
-    /// The context is captured with automatic location tracking.
-    fn with_context<B>(
-        self,
-        context: impl Into<String>,
-    ) -> Result<T, ContextualError<B>>
-    where
-        B: BizError + From<E>;
-
-    /// Convert error type without adding context
-    ///
-    /// This is a convenience method that converts the error type to a business
-    /// error. It's equivalent to `.map_err(B::from)`.
-    fn map_biz<B>(self) -> Result<T, B>
-    where
-        B: BizError + From<E>;
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Redacted<T>(T);
 
-    /// Add context conditionally
-    ///
-    /// This method adds context only when the condition is true.
-    /// If the condition is false, it still converts the error type but without
-    /// context.
-    fn with_context_if<B>(
-        self,
-        condition: bool,
-        context: impl Into<String>,
-    ) -> Result<T, ContextualError<B>>
-    where
-        B: BizError + From<E>;
-
-    /// Chain operations with error conversion
-    ///
-    /// This method allows you to chain operations while converting errors
-    /// to business error types. It's a convenience method that combines
-    /// `and_then` with automatic error type conversion.
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// use bizerror::*;
-    ///
-    /// #[derive(BizError, thiserror::Error)]
-    /// pub enum MyError {
-    ///     #[bizcode(8001)]
-    ///     #[error("IO error: {0}")]
-    ///     IoError(#[from] std::io::Error),
-    /// }
-    ///
-    /// let initial_err: Result<u32, std::io::Error> = Err(std::io::Error::new(
-    ///     std::io::ErrorKind::BrokenPipe,
-    ///     "pipe broken",
-    /// ));
-    /// let chained_err: Result<String, MyError> =
-    ///     initial_err.and_then_biz(|val| Ok(format!("Value is {val}")));
-    /// assert!(chained_err.is_err()); // true
-    /// ```
-    fn and_then_biz<U, F, B>(self, f: F) -> Result<U, B>
-    where
-        F: FnOnce(T) -> Result<U, B>,
-        B: BizError + From<E>;
-}
-
-impl<T, E: Error + 'static> ResultExt<T, E> for Result<T, E> {
-    #[track_caller]
-    fn with_context<B>(
-        self,
-        context: impl Into<String>,
-    ) -> Result<T, ContextualError<B>>
-    where
-        B: BizError + From<E>,
-    {
-        self.map_err(|e| ContextualError::new(B::from(e), context))
+impl<T> Redacted<T> {
+    /// Wrap a sensitive value
+    pub const fn new(value: T) -> Self {
+        Self(value)
     }
 
-    fn map_biz<B>(self) -> Result<T, B>
-    where
-        B: BizError + From<E>,
-    {
-        self.map_err(|e| B::from(e))
+    /// Explicitly access the hidden value
+    pub const fn reveal(&self) -> &T {
+        &self.0
     }
 
-    fn with_context_if<B>(
-        self,
-        condition: bool,
-        context: impl Into<String>,
-    ) -> Result<T, ContextualError<B>>
-    where
-        B: BizError + From<E>,
-    {
-        if condition {
-            self.with_context(context)
-        } else {
-            self.map_err(|e| {
-                ContextualError::new(B::from(e), "no context".to_string())
-            })
-        }
+    /// Unwrap the hidden value
+    pub fn into_inner(self) -> T {
+        self.0
     }
+}
 
-    fn and_then_biz<U, F, B>(self, f: F) -> Result<U, B>
-    where
-        F: FnOnce(T) -> Result<U, B>,
-        B: BizError + From<E>,
-    {
-        match self {
-            Ok(t) => f(t),
-            Err(e) => Err(B::from(e)),
-        }
+impl<T> From<T> for Redacted<T> {
+    fn from(value: T) -> Self {
+        Self(value)
     }
 }
 
-/// `BizError` extension trait
-///
-/// Provides convenient methods for adding context to business errors.
-/// This trait is automatically implemented for all types that implement
-/// `BizError`.
-///
-/// ## Example
-///
-/// ```rust
-/// use bizerror::*;
-///
-/// #[derive(BizError, thiserror::Error)]
-/// pub enum ApiError {
-///     #[bizcode(4001)]
-///     #[error("Validation failed")]
-///     ValidationError,
-/// }
-///
-/// let error = ApiError::ValidationError;
-/// let contextual = error.with_context("Processing user registration");
-/// ```
-pub trait BizErrorExt: BizError + Sized {
-    /// Add context with automatic location tracking
-    ///
-    /// This method wraps the error in a `ContextualError` with the provided
-    /// context and automatic location tracking.
-    #[track_caller]
-    fn with_context(self, context: impl Into<String>) -> ContextualError<Self> {
-        ContextualError::new(self, context)
+impl<T> core::fmt::Display for Redacted<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(REDACTED)
     }
 }
 
-impl<T: BizError> BizErrorExt for T {}
+impl<T> core::fmt::Debug for Redacted<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
 
 /// Option extension trait
 ///
@@ -906,137 +944,10 @@ pub trait OptionExt<T> {
     fn ok_or_biz<B>(self, error: B) -> Result<T, B>
     where
         B: BizError;
-}
 
-impl<T> OptionExt<T> for Option<T> {
-    fn ok_or_biz<B>(self, error: B) -> Result<T, B>
-    where
-        B: BizError,
-    {
-        self.ok_or(error)
-    }
-}
-
-/// Business errors collection for aggregating multiple errors
-///
-/// This type is useful for scenarios where you need to collect all errors
-/// instead of failing on the first one, such as form validation or batch
-/// processing.
-///
-/// ## Example
-///
-/// ```rust
-/// use bizerror::*;
-///
-/// #[derive(BizError, thiserror::Error)]
-/// pub enum ValidationError {
-///     #[bizcode(4001)]
-///     #[error("Invalid email: {email}")]
-///     InvalidEmail { email: String },
-///
-///     #[bizcode(4002)]
-///     #[error("Password too short")]
-///     PasswordTooShort,
-/// }
-///
-/// fn validate_user(
-///     email: &str,
-///     password: &str,
-/// ) -> Result<(), BizErrors<ValidationError>> {
-///     let mut errors = BizErrors::new();
-///
-///     if !email.contains('@') {
-///         errors.push_simple(ValidationError::InvalidEmail {
-///             email: email.to_string(),
-///         });
-///     }
-///
-///     if password.len() < 8 {
-///         errors.push_simple(ValidationError::PasswordTooShort);
-///     }
-///
-///     if errors.is_empty() {
-///         Ok(())
-///     } else {
-///         Err(errors)
-///     }
-/// }
-/// ```
-pub struct BizErrors<E: BizError> {
-    errors: Vec<ContextualError<E>>,
-}
-
-impl<E: BizError> BizErrors<E> {
-    /// Create a new empty error collection
-    pub const fn new() -> Self {
-        Self { errors: Vec::new() }
-    }
-
-    /// Create a new error collection with the given capacity
-    pub fn with_capacity(capacity: usize) -> Self {
-        Self {
-            errors: Vec::with_capacity(capacity),
-        }
-    }
-
-    /// Add a contextual error to the collection
-    pub fn push(&mut self, error: ContextualError<E>) {
-        self.errors.push(error);
-    }
-
-    /// Add a simple business error to the collection
+    /// Convert `None` to a `ContextualError` with lazily built context
     ///
-    /// The error will be wrapped in a `ContextualError` with minimal context.
-    #[track_caller]
-    pub fn push_simple(&mut self, error: E) {
-        self.errors.push(ContextualError::new(error, ""));
-    }
-
-    /// Add a business error with context to the collection
-    #[track_caller]
-    pub fn push_with_context(&mut self, error: E, context: impl Into<String>) {
-        self.errors.push(ContextualError::new(error, context));
-    }
-
-    /// Get the number of errors in the collection
-    pub const fn len(&self) -> usize {
-        self.errors.len()
-    }
-
-    /// Check if the error collection is empty
-    pub const fn is_empty(&self) -> bool {
-        self.errors.is_empty()
-    }
-
-    /// Get an iterator over the errors
-    pub fn iter(&self) -> impl Iterator<Item = &ContextualError<E>> {
-        self.errors.iter()
-    }
-
-    /// Get a reference to the errors vector
-    pub fn as_slice(&self) -> &[ContextualError<E>] {
-        &self.errors
-    }
-
-    /// Convert into the underlying errors vector
-    pub fn into_vec(self) -> Vec<ContextualError<E>> {
-        self.errors
-    }
-
-    /// Get the first error in the collection
-    pub fn first(&self) -> Option<&ContextualError<E>> {
-        self.errors.first()
-    }
-
-    /// Get the last error in the collection
-    pub fn last(&self) -> Option<&ContextualError<E>> {
-        self.errors.last()
-    }
-
-    /// Collect successful results and errors from an iterator
-    ///
-    /// Returns a tuple containing all successful values and optionally
-    /// the collected errors (if any occurred).
+    /// The closure only runs when the option is `None`.
     ///
     /// # Example
     ///
@@ -1044,269 +955,149 @@ impl<E: BizError> BizErrors<E> {
     /// use bizerror::*;
     ///
     /// #[derive(BizError, thiserror::Error)]
-    /// pub enum ProcessError {
-    ///     #[bizcode(5001)]
-    ///     #[error("Invalid value: {value}")]
-    ///     InvalidValue { value: i32 },
-    /// }
-    ///
-    /// let results: Vec<Result<i32, ContextualError<ProcessError>>> = vec![
-    ///     Ok(1),
-    ///     Ok(2),
-    ///     Err(ProcessError::InvalidValue { value: 3 }
-    ///         .with_context("Processing item 3")),
-    ///     Ok(4),
-    ///     Err(ProcessError::InvalidValue { value: 5 }
-    ///         .with_context("Processing item 5")),
-    /// ];
-    ///
-    /// let (successes, errors) = BizErrors::collect_from(results.into_iter());
-    /// assert_eq!(successes, vec![1, 2, 4]);
-    /// assert!(errors.is_some());
-    /// assert_eq!(errors.unwrap().len(), 2);
-    /// ```
-    pub fn collect_from<T, I>(iter: I) -> (Vec<T>, Option<Self>)
-    where
-        I: Iterator<Item = Result<T, ContextualError<E>>>,
-    {
-        let mut successes = Vec::new();
-        let mut errors = Self::new();
-
-        for result in iter {
-            match result {
-                Ok(value) => successes.push(value),
-                Err(error) => errors.push(error),
-            }
-        }
-
-        let errors = if errors.is_empty() {
-            None
-        } else {
-            Some(errors)
-        };
-
-        (successes, errors)
-    }
-
-    /// Collect all errors from an iterator of Results
-    ///
-    /// Returns `None` if no errors occurred, or `Some(BizErrors)` with all
-    /// errors.
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// use bizerror::*;
-    ///
-    /// #[derive(BizError, thiserror::Error)]
-    /// pub enum ValidationError {
+    /// pub enum MyError {
     ///     #[bizcode(4001)]
-    ///     #[error("Invalid field")]
-    ///     InvalidField,
+    ///     #[error("User not found")]
+    ///     UserNotFound,
     /// }
     ///
-    /// let results: Vec<Result<(), ContextualError<ValidationError>>> = vec![
-    ///     Ok(()),
-    ///     Err(ValidationError::InvalidField.with_context("Field 1")),
-    ///     Err(ValidationError::InvalidField.with_context("Field 2")),
-    /// ];
-    ///
-    /// let errors = BizErrors::collect_errors(results.into_iter());
-    /// assert!(errors.is_some());
-    /// assert_eq!(errors.unwrap().len(), 2);
+    /// let id = 123;
+    /// let result: Result<String, _> = None
+    ///     .with_context_lazy(MyError::UserNotFound, || {
+    ///         format!("Looking up {id}")
+    ///     });
+    /// assert_eq!(result.unwrap_err().context(), "Looking up 123");
     /// ```
-    pub fn collect_errors<T, I>(iter: I) -> Option<Self>
+    #[cfg(feature = "alloc")]
+    fn with_context_lazy<B, C, F>(
+        self,
+        error: B,
+        context: F,
+    ) -> Result<T, ContextualError<B>>
     where
-        I: Iterator<Item = Result<T, ContextualError<E>>>,
-    {
-        let mut errors = Self::new();
-
-        for result in iter {
-            if let Err(error) = result {
-                errors.push(error);
-            }
-        }
-
-        if errors.is_empty() {
-            None
-        } else {
-            Some(errors)
-        }
-    }
+        B: BizError,
+        C: Into<alloc::string::String>,
+        F: FnOnce() -> C;
+}
 
-    /// Check if any error in the collection has the specified code
-    pub fn contains_code<C>(&self, code: C) -> bool
+impl<T> OptionExt<T> for Option<T> {
+    fn ok_or_biz<B>(self, error: B) -> Result<T, B>
     where
-        C: PartialEq<E::CodeType> + Copy,
+        B: BizError,
     {
-        self.errors.iter().any(|error| code == error.code())
-    }
-
-    /// Get all unique error codes in the collection
-    pub fn error_codes(&self) -> Vec<E::CodeType> {
-        let mut codes: Vec<E::CodeType> =
-            self.errors.iter().map(BizError::code).collect();
-        codes.sort_by(|a, b| format!("{a:?}").cmp(&format!("{b:?}")));
-        codes.dedup();
-        codes
+        self.ok_or(error)
     }
 
-    /// Filter errors by a predicate
-    ///
-    /// Returns an iterator over the errors that satisfy the given predicate.
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// use bizerror::*;
-    ///
-    /// #[derive(BizError, thiserror::Error)]
-    /// pub enum MyError {
-    ///     #[bizcode(4001)]
-    ///     #[error("Validation error")]
-    ///     ValidationError,
-    ///
-    ///     #[bizcode(8001)]
-    ///     #[error("System error")]
-    ///     SystemError,
-    /// }
-    ///
-    /// let mut errors = BizErrors::new();
-    /// errors.push_simple(MyError::ValidationError);
-    /// errors.push_simple(MyError::SystemError);
-    ///
-    /// // Filter only validation errors (4xxx codes)
-    /// let validation_errors: Vec<_> = errors
-    ///     .filter(|e| e.code() >= 4000 && e.code() < 5000)
-    ///     .collect();
-    /// assert_eq!(validation_errors.len(), 1);
-    /// ```
-    pub fn filter<F>(
-        &self,
-        predicate: F,
-    ) -> impl Iterator<Item = &ContextualError<E>>
+    #[cfg(feature = "alloc")]
+    #[track_caller]
+    fn with_context_lazy<B, C, F>(
+        self,
+        error: B,
+        context: F,
+    ) -> Result<T, ContextualError<B>>
     where
-        F: Fn(&ContextualError<E>) -> bool,
+        B: BizError,
+        C: Into<alloc::string::String>,
+        F: FnOnce() -> C,
     {
-        self.errors.iter().filter(move |e| predicate(*e))
-    }
-}
-
-impl<E: BizError> Default for BizErrors<E> {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl<E: BizError> IntoIterator for BizErrors<E> {
-    type Item = ContextualError<E>;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
-    fn into_iter(self) -> Self::IntoIter {
-        self.errors.into_iter()
-    }
-}
-
-impl<E: BizError> std::fmt::Debug for BizErrors<E> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.errors.is_empty() {
-            f.debug_struct("BizErrors").field("count", &0).finish()
-        } else if self.errors.len() == 1 {
-            f.debug_struct("BizErrors")
-                .field("count", &1)
-                .field("error", &self.errors[0])
-                .finish()
-        } else {
-            let mut debug_struct = f.debug_struct("BizErrors");
-            debug_struct.field("count", &self.errors.len());
-
-            let codes: Vec<_> =
-                self.errors.iter().map(BizError::code).collect();
-            debug_struct.field("codes", &codes);
-
-            // Show first few errors for detailed view
-            if self.errors.len() <= 3 {
-                debug_struct.field("errors", &self.errors);
-            } else {
-                debug_struct.field("first_3_errors", &&self.errors[0..3]);
-                debug_struct.field(
-                    "note",
-                    &format!("... and {} more", self.errors.len() - 3),
-                );
-            }
-
-            debug_struct.finish()
+        match self {
+            Some(value) => Ok(value),
+            None => Err(ContextualError::new(error, context())),
         }
     }
 }
 
-impl<E: BizError> std::fmt::Display for BizErrors<E> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.errors.is_empty() {
-            write!(f, "No errors")
-        } else if self.errors.len() == 1 {
-            write!(f, "{}", self.errors[0])
-        } else {
-            writeln!(
-                f,
-                "Multiple errors occurred ({} total):",
-                self.errors.len()
-            )?;
-            for (i, error) in self.errors.iter().enumerate() {
-                writeln!(f, "  {}. {}", i + 1, error)?;
+/// Support code for the derive macro. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::{
+        borrow::Cow,
+        vec::Vec,
+    };
+    use core::fmt;
+
+    #[cfg(feature = "serde")]
+    pub use serde;
+
+    #[cfg(feature = "alloc")]
+    pub use crate::fields::{
+        FieldProbe,
+        ViaFallback,
+        ViaToBizFieldValue,
+    };
+    #[cfg(feature = "serde")]
+    pub use crate::serialize::serialize_biz_error;
+
+    /// Renders a value's `Display` output as a quoted `Debug` string without
+    /// allocating, escaped like the `Debug` output of `str`.
+    pub struct DisplayAsDebug<'a, T: ?Sized>(pub &'a T);
+
+    impl<T: fmt::Display + ?Sized> fmt::Debug for DisplayAsDebug<'_, T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            struct Escape<'a, 'b>(&'a mut fmt::Formatter<'b>);
+
+            impl fmt::Write for Escape<'_, '_> {
+                fn write_str(&mut self, s: &str) -> fmt::Result {
+                    // `str` leaves single quotes alone, unlike `char`
+                    for c in s.chars() {
+                        if c == '\'' {
+                            fmt::Write::write_char(self.0, c)?;
+                        } else {
+                            fmt::Display::fmt(&c.escape_debug(), self.0)?;
+                        }
+                    }
+                    Ok(())
+                }
             }
-            Ok(())
-        }
-    }
-}
 
-impl<E: BizError> Error for BizErrors<E> {
-    fn source(&self) -> Option<&(dyn Error + 'static)> {
-        // Return the first error as the source
-        self.errors.first().map(|e| e as &dyn Error)
+            f.write_str("\"")?;
+            fmt::write(&mut Escape(f), format_args!("{}", self.0))?;
+            f.write_str("\"")
+        }
     }
 }
 
-impl<E: BizError> BizError for BizErrors<E> {
-    type CodeType = E::CodeType;
-
-    fn code(&self) -> Self::CodeType {
-        // Return the code of the first error
-        self.errors
-            .first()
-            .map_or_else(|| panic!("BizErrors is empty"), BizError::code)
-    }
-
-    fn name(&self) -> &'static str {
-        "BizErrors"
-    }
+/// Expands its input only when the `alloc` feature of `bizerror` is enabled.
+/// Used by the derive macro for items that need allocation.
+#[doc(hidden)]
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! __bizerror_alloc {
+    ($($tokens:tt)*) => {
+        $($tokens)*
+    };
 }
 
-impl<'a, E: BizError> IntoIterator for &'a BizErrors<E> {
-    type Item = &'a ContextualError<E>;
-    type IntoIter = std::slice::Iter<'a, ContextualError<E>>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        self.errors.iter()
-    }
+/// Expands its input only when the `alloc` feature of `bizerror` is enabled.
+/// Used by the derive macro for items that need allocation.
+#[doc(hidden)]
+#[cfg(not(feature = "alloc"))]
+#[macro_export]
+macro_rules! __bizerror_alloc {
+    ($($tokens:tt)*) => {};
 }
 
-// Allow collecting Results into BizErrors
-impl<E: BizError> FromIterator<ContextualError<E>> for BizErrors<E> {
-    fn from_iter<T: IntoIterator<Item = ContextualError<E>>>(iter: T) -> Self {
-        Self {
-            errors: iter.into_iter().collect(),
-        }
-    }
+/// Expands its input only when the `serde` feature of `bizerror` is enabled.
+/// Used by the derive macro for `#[bizconfig(serde)]`.
+#[doc(hidden)]
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! __bizerror_serde {
+    ($($tokens:tt)*) => {
+        $($tokens)*
+    };
 }
 
-impl<E: BizError> FromIterator<E> for BizErrors<E> {
-    #[track_caller]
-    fn from_iter<T: IntoIterator<Item = E>>(iter: T) -> Self {
-        Self {
-            errors: iter
-                .into_iter()
-                .map(|e| ContextualError::new(e, ""))
-                .collect(),
-        }
-    }
+/// Expands its input only when the `serde` feature of `bizerror` is enabled.
+/// Used by the derive macro for `#[bizconfig(serde)]`.
+#[doc(hidden)]
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+macro_rules! __bizerror_serde {
+    ($($tokens:tt)*) => {
+        compile_error!(
+            "`#[bizconfig(serde)]` requires the `serde` feature of bizerror"
+        );
+    };
 }
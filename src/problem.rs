@@ -0,0 +1,452 @@
+//! RFC 9457 Problem Details for business errors
+
+use alloc::{
+    borrow::Cow,
+    string::{
+        String,
+        ToString,
+    },
+    vec::Vec,
+};
+use core::ops::RangeInclusive;
+
+use crate::{
+    BizCode,
+    BizError,
+    BizErrors,
+    BizFieldValue,
+    ChainLink,
+    ContextFrame,
+    ContextualError,
+    DynBizError,
+    ReportEntry,
+    Reportable,
+};
+
+/// Maps a business error to an HTTP status code
+pub type StatusMapper = fn(&dyn DynBizError) -> u16;
+
+/// Maps inclusive ranges of integer business codes to HTTP status codes
+pub type StatusRanges = &'static [(RangeInclusive<i128>, u16)];
+
+/// How business errors become [`ProblemDetails`]
+///
+/// By default the `type` is the error's [`doc_url`](BizError::doc_url) or
+/// `about:blank`, the status follows
+/// [`DEFAULT_STATUS_RANGES`](Self::DEFAULT_STATUS_RANGES) (`4000..=4999` is
+/// `400`, everything else `500`), and context frames and sources are
+/// included. Call [`production`](Self::production) to omit them from public
+/// responses.
+///
+/// A status set on the variant with `#[bizhttp(...)]` always wins; the
+/// configured status only applies to the other variants.
+///
+/// # Example
+///
+/// ```rust
+/// use bizerror::*;
+///
+/// #[derive(BizError, thiserror::Error)]
+/// pub enum ApiError {
+///     #[bizcode(4004)]
+///     #[error("User not found")]
+///     UserNotFound,
+/// }
+///
+/// const PROBLEMS: ProblemConfig = ProblemConfig::new()
+///     .type_template("https://errors.example.com/{code}")
+///     .status_with(|error| match error.code().as_int() {
+///         Some(4000..=4999) => 404,
+///         _ => 500,
+///     })
+///     .production();
+///
+/// let error = ApiError::UserNotFound.with_context("Loading profile");
+/// let problem = PROBLEMS.problem(&error);
+/// assert_eq!(problem.type_uri(), "https://errors.example.com/4004");
+/// assert_eq!(problem.title(), "UserNotFound");
+/// assert_eq!(problem.status(), 404);
+/// assert_eq!(problem.detail(), "User not found");
+/// assert!(problem.context().is_empty());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ProblemConfig {
+    type_template:  Option<&'static str>,
+    status:         Status,
+    expose_context: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Status {
+    Fixed(u16),
+    Mapped(StatusMapper),
+    Ranges(StatusRanges, u16),
+}
+
+impl ProblemConfig {
+    /// The status ranges of the default configuration: `4xxx` codes are
+    /// client errors, anything else falls back to `500`
+    pub const DEFAULT_STATUS_RANGES: StatusRanges = &[(4000..=4999, 400)];
+
+    /// Create the default configuration
+    pub const fn new() -> Self {
+        Self {
+            type_template:  None,
+            status:         Status::Ranges(Self::DEFAULT_STATUS_RANGES, 500),
+            expose_context: true,
+        }
+    }
+
+    /// Set the `type` URI template; `{code}` and `{name}` are replaced by the
+    /// business code and name
+    #[must_use]
+    pub const fn type_template(mut self, template: &'static str) -> Self {
+        self.type_template = Some(template);
+        self
+    }
+
+    /// Use the same status for every error
+    #[must_use]
+    pub const fn status(mut self, status: u16) -> Self {
+        self.status = Status::Fixed(status);
+        self
+    }
+
+    /// Look up the status by code range, using `fallback` for string codes
+    /// and codes outside every range
+    ///
+    /// The first matching range wins.
+    #[must_use]
+    pub const fn status_ranges(
+        mut self,
+        ranges: StatusRanges,
+        fallback: u16,
+    ) -> Self {
+        self.status = Status::Ranges(ranges, fallback);
+        self
+    }
+
+    /// Compute the status of each error with `status`
+    #[must_use]
+    pub const fn status_with(mut self, status: StatusMapper) -> Self {
+        self.status = Status::Mapped(status);
+        self
+    }
+
+    /// Omit context frames, locations and sources
+    #[must_use]
+    pub const fn production(mut self) -> Self {
+        self.expose_context = false;
+        self
+    }
+
+    /// Build problem details for a [`ContextualError`] or, with every error
+    /// listed in the `errors` member, for [`BizErrors`]
+    ///
+    /// A `BizErrors` holding a single error is reported as that error, and an
+    /// empty one as a `500` "No business error" problem.
+    pub fn problem<R: Reportable + ?Sized>(&self, error: &R) -> ProblemDetails {
+        let entries = error.report_entries();
+        match entries.as_slice() {
+            [] => ProblemDetails::summary(
+                "No business error",
+                Cow::Borrowed("No errors occurred"),
+                Vec::new(),
+            ),
+            [entry] => self.entry(entry),
+            entries => ProblemDetails::summary(
+                "Multiple business errors",
+                Cow::Owned(alloc::format!("{} errors occurred", entries.len())),
+                entries.iter().map(|entry| self.entry(entry)).collect(),
+            ),
+        }
+    }
+
+    /// Build problem details for a business error without context
+    pub fn biz_problem<E: BizError>(&self, error: &E) -> ProblemDetails {
+        self.problem(error as &dyn DynBizError)
+    }
+
+    /// Get the HTTP status of `error`
+    pub fn status_of(&self, error: &dyn DynBizError) -> u16 {
+        if let Some(status) = error.biz_http_status() {
+            return status;
+        }
+        match self.status {
+            Status::Fixed(status) => status,
+            Status::Mapped(status) => status(error),
+            Status::Ranges(ranges, fallback) => error
+                .code()
+                .as_int()
+                .and_then(|code| {
+                    ranges
+                        .iter()
+                        .find(|(range, _)| range.contains(&code))
+                        .map(|(_, status)| *status)
+                })
+                .unwrap_or(fallback),
+        }
+    }
+
+    /// Whether context frames and sources are exposed
+    #[cfg(feature = "tonic")]
+    pub(crate) const fn exposes_context(&self) -> bool {
+        self.expose_context
+    }
+
+    fn entry(&self, entry: &ReportEntry<'_>) -> ProblemDetails {
+        let code = entry.code();
+        let type_uri = self.type_template.map_or_else(
+            || {
+                Cow::Borrowed(
+                    entry.biz_error().biz_doc_url().unwrap_or(ABOUT_BLANK),
+                )
+            },
+            |template| {
+                Cow::Owned(
+                    template
+                        .replace("{code}", &code.to_string())
+                        .replace("{name}", entry.name()),
+                )
+            },
+        );
+        let (context, sources) = if self.expose_context {
+            (
+                entry.frames().iter().map(|&frame| frame.clone()).collect(),
+                entry.causes().iter().map(ChainLink::message).collect(),
+            )
+        } else {
+            (Vec::new(), Vec::new())
+        };
+        ProblemDetails {
+            type_uri,
+            title: Cow::Owned(entry.name().to_string()),
+            status: self.status_of(entry.biz_error()),
+            detail: Cow::Owned(entry.message().into_owned()),
+            instance: None,
+            code: Some(code),
+            fields: entry.fields(),
+            context,
+            sources,
+            errors: Vec::new(),
+        }
+    }
+}
+
+impl Default for ProblemConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+static CONFIG: std::sync::RwLock<ProblemConfig> =
+    std::sync::RwLock::new(ProblemConfig::new());
+
+/// Install the process-wide [`ProblemConfig`]
+///
+/// It is used by the `From` conversions into [`ProblemDetails`] and by the
+/// HTTP integrations.
+///
+/// # Example
+///
+/// ```rust
+/// use bizerror::*;
+///
+/// set_problem_config(
+///     ProblemConfig::new()
+///         .status_ranges(&[(4000..=4999, 400), (8000..=8999, 503)], 500)
+///         .production(),
+/// );
+/// # set_problem_config(ProblemConfig::new());
+/// ```
+#[cfg(feature = "std")]
+pub fn set_problem_config(config: ProblemConfig) {
+    *CONFIG
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = config;
+}
+
+impl ProblemConfig {
+    /// Get the process-wide configuration, see [`set_problem_config`]
+    ///
+    /// Without the `std` feature this is the default configuration.
+    #[cfg_attr(not(feature = "std"), allow(clippy::missing_const_for_fn))]
+    pub fn current() -> Self {
+        #[cfg(feature = "std")]
+        return *CONFIG
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        #[cfg(not(feature = "std"))]
+        Self::new()
+    }
+}
+
+const ABOUT_BLANK: &str = "about:blank";
+
+/// An RFC 9457 problem details object
+///
+/// Built by a [`ProblemConfig`]. The standard members are `type`, `title`
+/// (the business name), `status`, `detail` (the message, redacted for
+/// sensitive errors) and `instance`. Extension members are `code`, `fields`,
+/// `context` and `sources` and, for several errors, `errors`. With the
+/// `serde` feature it serializes to `application/problem+json`, leaving out
+/// empty members.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProblemDetails {
+    type_uri: Cow<'static, str>,
+    title:    Cow<'static, str>,
+    status:   u16,
+    detail:   Cow<'static, str>,
+    instance: Option<String>,
+    code:     Option<BizCode>,
+    fields:   Vec<(Cow<'static, str>, BizFieldValue)>,
+    context:  Vec<ContextFrame>,
+    sources:  Vec<String>,
+    errors:   Vec<Self>,
+}
+
+impl ProblemDetails {
+    /// The media type of serialized problem details
+    pub const CONTENT_TYPE: &'static str = "application/problem+json";
+
+    /// Summarize several errors, or none, under a generic title
+    fn summary(
+        title: &'static str,
+        detail: Cow<'static, str>,
+        errors: Vec<Self>,
+    ) -> Self {
+        Self {
+            type_uri: Cow::Borrowed(ABOUT_BLANK),
+            title: Cow::Borrowed(title),
+            // The most severe status wins
+            status: errors
+                .iter()
+                .map(|problem| problem.status)
+                .max()
+                .unwrap_or(500),
+            detail,
+            instance: None,
+            code: None,
+            fields: Vec::new(),
+            context: Vec::new(),
+            sources: Vec::new(),
+            errors,
+        }
+    }
+
+    /// Set the `instance` URI identifying this occurrence
+    #[must_use]
+    pub fn with_instance(mut self, instance: impl Into<String>) -> Self {
+        self.instance = Some(instance.into());
+        self
+    }
+
+    /// Get the `type` URI
+    pub fn type_uri(&self) -> &str {
+        &self.type_uri
+    }
+
+    /// Get the `title`, the business error name
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Get the HTTP status code
+    pub const fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// Get the `detail`, the error message
+    pub fn detail(&self) -> &str {
+        &self.detail
+    }
+
+    /// Get the `instance` URI
+    pub fn instance(&self) -> Option<&str> {
+        self.instance.as_deref()
+    }
+
+    /// Get the business code; `None` when listing several errors or none
+    pub const fn code(&self) -> Option<&BizCode> {
+        self.code.as_ref()
+    }
+
+    /// Get the structured fields
+    pub fn fields(&self) -> &[(Cow<'static, str>, BizFieldValue)] {
+        &self.fields
+    }
+
+    /// Get the context frames, innermost first; empty in production mode
+    pub fn context(&self) -> &[ContextFrame] {
+        &self.context
+    }
+
+    /// Get the source chain messages; empty in production mode
+    pub fn sources(&self) -> &[String] {
+        &self.sources
+    }
+
+    /// Get the listed errors, one per error of a `BizErrors`
+    pub fn errors(&self) -> &[Self] {
+        &self.errors
+    }
+}
+
+impl<E: BizError> From<&ContextualError<E>> for ProblemDetails {
+    fn from(error: &ContextualError<E>) -> Self {
+        ProblemConfig::current().problem(error)
+    }
+}
+
+impl<E: BizError> From<&BizErrors<E>> for ProblemDetails {
+    fn from(errors: &BizErrors<E>) -> Self {
+        ProblemConfig::current().problem(errors)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ProblemDetails {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        struct Fields<'a>(&'a [(Cow<'static, str>, BizFieldValue)]);
+
+        impl serde::Serialize for Fields<'_> {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                serializer.collect_map(self.0.iter().map(|(k, v)| (k, v)))
+            }
+        }
+
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", &self.type_uri)?;
+        map.serialize_entry("title", &self.title)?;
+        map.serialize_entry("status", &self.status)?;
+        map.serialize_entry("detail", &self.detail)?;
+        if let Some(instance) = &self.instance {
+            map.serialize_entry("instance", instance)?;
+        }
+        if let Some(code) = &self.code {
+            map.serialize_entry("code", code)?;
+        }
+        if !self.fields.is_empty() {
+            map.serialize_entry("fields", &Fields(&self.fields))?;
+        }
+        if !self.context.is_empty() {
+            map.serialize_entry("context", &self.context)?;
+        }
+        if !self.sources.is_empty() {
+            map.serialize_entry("sources", &self.sources)?;
+        }
+        if !self.errors.is_empty() {
+            map.serialize_entry("errors", &self.errors)?;
+        }
+        map.end()
+    }
+}
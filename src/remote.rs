@@ -0,0 +1,499 @@
+//! Business errors received from other services, behind the `serde` and
+//! `std` features
+
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    string::{
+        String,
+        ToString,
+    },
+    sync::Arc,
+    vec::Vec,
+};
+use core::{
+    error::Error,
+    fmt,
+};
+
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    de::{
+        MapAccess,
+        Visitor,
+    },
+    ser::SerializeStruct,
+};
+
+use crate::{
+    BizCode,
+    BizError,
+    BizFieldValue,
+    FromBizCode,
+    serialize::{
+        serialize_biz_fields,
+        serialize_source,
+    },
+};
+
+/// Code of a [`RemoteBizError`]
+///
+/// String codes are owned by the error and shared between its copies.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RemoteCode {
+    Int(i128),
+    Str(Arc<str>),
+}
+
+impl RemoteCode {
+    /// Get the code as an integer, if it is one
+    pub const fn as_int(&self) -> Option<i128> {
+        match self {
+            Self::Int(code) => Some(*code),
+            Self::Str(_) => None,
+        }
+    }
+
+    /// Get the code as a string, if it is one
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Int(_) => None,
+            Self::Str(code) => Some(code),
+        }
+    }
+}
+
+impl fmt::Display for RemoteCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(code) => write!(f, "{code}"),
+            Self::Str(code) => f.write_str(code),
+        }
+    }
+}
+
+impl From<BizCode> for RemoteCode {
+    fn from(code: BizCode) -> Self {
+        match code {
+            BizCode::Int(code) => Self::Int(code),
+            BizCode::Str(code) => Self::Str(code.into()),
+        }
+    }
+}
+
+impl From<RemoteCode> for BizCode {
+    fn from(code: RemoteCode) -> Self {
+        match code {
+            RemoteCode::Int(code) => Self::Int(code),
+            RemoteCode::Str(code) => Self::Str(Cow::Owned(code.to_string())),
+        }
+    }
+}
+
+/// A context frame of a [`RemoteBizError`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteFrame {
+    pub(crate) message:  String,
+    #[serde(default)]
+    pub(crate) location: Option<String>,
+}
+
+impl RemoteFrame {
+    /// Get the context message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Get the `file:line:col` where the context was added on the remote side
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+}
+
+impl fmt::Display for RemoteFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.location, f.alternate()) {
+            (Some(location), true) => {
+                write!(f, "{}, at {location}", self.message)
+            }
+            _ => f.write_str(&self.message),
+        }
+    }
+}
+
+/// A source of a [`RemoteBizError`]
+///
+/// Sources that were business errors on the remote side keep their code and
+/// name; other sources only have a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteCause {
+    pub(crate) message:   String,
+    pub(crate) code:      Option<RemoteCode>,
+    pub(crate) name:      Option<String>,
+    pub(crate) sensitive: bool,
+    pub(crate) source:    Option<Box<Self>>,
+}
+
+impl RemoteCause {
+    /// A cause known only by its message
+    pub(crate) const fn message_only(message: String) -> Self {
+        Self {
+            message,
+            code: None,
+            name: None,
+            sensitive: false,
+            source: None,
+        }
+    }
+
+    /// Get the message, `<redacted>` for sensitive business errors
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Get the business code, if the source was a business error
+    pub const fn code(&self) -> Option<&RemoteCode> {
+        self.code.as_ref()
+    }
+
+    /// Get the business error name, if the source was a business error
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Whether the source was a sensitive business error
+    pub const fn is_sensitive(&self) -> bool {
+        self.sensitive
+    }
+}
+
+impl fmt::Display for RemoteCause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for RemoteCause {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_deref().map(|source| source as &dyn Error)
+    }
+}
+
+/// A business error reconstructed from its serialized form
+///
+/// Deserializes from what a [`ContextualError`](crate::ContextualError) or a
+/// `#[bizconfig(serde)]` error serializes to, and serializes back to the
+/// same layout, so it can be forwarded unchanged. The remote sources become
+/// a chain of [`RemoteCause`]s behind [`Error::source`].
+///
+/// # Example
+///
+/// ```rust
+/// use bizerror::*;
+///
+/// #[derive(BizError, thiserror::Error)]
+/// #[bizconfig(serde)]
+/// pub enum OrderError {
+///     #[bizcode(3001)]
+///     #[error("Order not found")]
+///     NotFound,
+/// }
+///
+/// let json = r#"{"code":3001,"name":"NotFound","message":"Order not found",
+///     "context":[{"message":"Loading order","location":"orders.rs:10:5"}]}"#;
+/// let remote: RemoteBizError = serde_json::from_str(json).unwrap();
+/// assert_eq!(remote.code(), RemoteCode::Int(3001));
+/// assert_eq!(remote.context()[0].message(), "Loading order");
+///
+/// let local = remote.try_into_typed::<OrderError>().unwrap();
+/// assert!(matches!(local, OrderError::NotFound));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoteBizError {
+    code:      RemoteCode,
+    name:      String,
+    message:   String,
+    fields:    Vec<(Cow<'static, str>, BizFieldValue)>,
+    context:   Vec<RemoteFrame>,
+    location:  Option<String>,
+    sensitive: bool,
+    source:    Option<Box<RemoteCause>>,
+}
+
+impl RemoteBizError {
+    /// Assemble an error from its received parts
+    pub(crate) fn from_parts(
+        code: RemoteCode,
+        name: String,
+        message: String,
+        fields: Vec<(String, BizFieldValue)>,
+        context: Vec<RemoteFrame>,
+        location: Option<String>,
+        sources: Vec<RemoteCause>,
+    ) -> Self {
+        let source = sources.into_iter().rev().fold(None, |source, cause| {
+            Some(Box::new(RemoteCause { source, ..cause }))
+        });
+        Self {
+            code,
+            name,
+            message,
+            fields: fields
+                .into_iter()
+                .map(|(name, value)| (Cow::Owned(name), value))
+                .collect(),
+            context,
+            location,
+            sensitive: false,
+            source,
+        }
+    }
+
+    /// Mark the error as sensitive, as flagged by the sender
+    pub(crate) const fn sensitive(mut self, sensitive: bool) -> Self {
+        self.sensitive = sensitive;
+        self
+    }
+
+    /// Get the context frames, innermost first
+    pub fn context(&self) -> &[RemoteFrame] {
+        &self.context
+    }
+
+    /// Get the `file:line:col` of the outermost context on the remote side
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+
+    /// Get a field value by name
+    pub fn field(&self, name: &str) -> Option<&BizFieldValue> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Iterate over the remote source chain, outermost first
+    pub fn causes(&self) -> impl Iterator<Item = &RemoteCause> {
+        core::iter::successors(self.source.as_deref(), |cause| {
+            cause.source.as_deref()
+        })
+    }
+
+    /// Iterate over the messages of the remote source chain, outermost first
+    pub fn sources(&self) -> impl Iterator<Item = &str> {
+        self.causes().map(RemoteCause::message)
+    }
+
+    /// Rebuild the local error with the same code
+    ///
+    /// # Errors
+    ///
+    /// Returns `self` unchanged if `E` has no variant for the code.
+    #[allow(clippy::result_large_err)]
+    pub fn try_into_typed<E: FromBizCode>(self) -> Result<E, Self> {
+        self.try_into_typed_with(|remote| {
+            E::from_biz_code(&remote.code.clone().into())
+        })
+    }
+
+    /// Rebuild a local error with `lookup`, e.g. for variants with fields
+    ///
+    /// # Errors
+    ///
+    /// Returns `self` unchanged if `lookup` returns `None`.
+    #[allow(clippy::result_large_err)]
+    pub fn try_into_typed_with<E>(
+        self,
+        lookup: impl FnOnce(&Self) -> Option<E>,
+    ) -> Result<E, Self> {
+        lookup(&self).ok_or(self)
+    }
+}
+
+impl fmt::Display for RemoteBizError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for RemoteBizError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_deref().map(|source| source as &dyn Error)
+    }
+}
+
+impl BizError for RemoteBizError {
+    type CodeType = RemoteCode;
+
+    fn code(&self) -> Self::CodeType {
+        self.code.clone()
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn fields(&self) -> Vec<(Cow<'static, str>, BizFieldValue)> {
+        self.fields.clone()
+    }
+
+    // A redacted message stays redacted when forwarded
+    fn is_sensitive(&self) -> bool {
+        self.sensitive
+    }
+}
+
+/// Serializes like a [`ContextualError`](crate::ContextualError)
+impl Serialize for RemoteBizError {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut error = serializer.serialize_struct("RemoteBizError", 8)?;
+        serialize_biz_fields(&mut error, self)?;
+        error.serialize_field("context", &self.context)?;
+        error.serialize_field("location", &self.location)?;
+        error.serialize_field("sources", &Causes(self))?;
+        error.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for RemoteBizError {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let wire = WireError::deserialize(deserializer)?;
+        let sources = wire.sources.into_iter().map(RemoteCause::from).collect();
+        Ok(Self::from_parts(
+            wire.code.into(),
+            wire.name,
+            wire.message,
+            wire.fields.0,
+            wire.context,
+            wire.location,
+            sources,
+        )
+        .sensitive(wire.sensitive))
+    }
+}
+
+/// Serializes the source chain like the `sources` of a
+/// [`ContextualError`](crate::ContextualError)
+struct Causes<'a>(&'a RemoteBizError);
+
+impl Serialize for Causes<'_> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.causes())
+    }
+}
+
+/// Serializes as `{message, code, name, sensitive}`, the code and name only
+/// for business errors and the flag only when set
+impl Serialize for RemoteCause {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let code = self.code.clone().map(BizCode::from);
+        serialize_source(
+            serializer,
+            &self.message,
+            code.as_ref(),
+            self.name.as_deref(),
+            self.sensitive,
+        )
+    }
+}
+
+/// The serialized layout; everything but code, name and message is optional
+#[derive(Deserialize)]
+struct WireError {
+    code:      BizCode,
+    name:      String,
+    message:   String,
+    #[serde(default)]
+    fields:    WireFields,
+    #[serde(default)]
+    context:   Vec<RemoteFrame>,
+    #[serde(default)]
+    location:  Option<String>,
+    #[serde(default)]
+    sensitive: bool,
+    #[serde(default)]
+    sources:   Vec<WireSource>,
+}
+
+/// A serialized source, a bare message in the original layout
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum WireSource {
+    Message(String),
+    Cause {
+        message:   String,
+        #[serde(default)]
+        code:      Option<BizCode>,
+        #[serde(default)]
+        name:      Option<String>,
+        #[serde(default)]
+        sensitive: bool,
+    },
+}
+
+impl From<WireSource> for RemoteCause {
+    fn from(source: WireSource) -> Self {
+        match source {
+            WireSource::Message(message) => Self::message_only(message),
+            WireSource::Cause {
+                message,
+                code,
+                name,
+                sensitive,
+            } => Self {
+                code: code.map(Into::into),
+                name,
+                sensitive,
+                ..Self::message_only(message)
+            },
+        }
+    }
+}
+
+/// Fields in their serialized order
+#[derive(Default)]
+struct WireFields(Vec<(String, BizFieldValue)>);
+
+impl<'de> Deserialize<'de> for WireFields {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        struct FieldsVisitor;
+
+        impl<'de> Visitor<'de> for FieldsVisitor {
+            type Value = WireFields;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a map of field names to values")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<WireFields, A::Error> {
+                let mut fields = Vec::new();
+                while let Some(field) = map.next_entry()? {
+                    fields.push(field);
+                }
+                Ok(WireFields(fields))
+            }
+        }
+
+        deserializer.deserialize_map(FieldsVisitor)
+    }
+}
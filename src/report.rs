@@ -0,0 +1,407 @@
+//! Rendering of contextual errors in selectable output styles
+
+use alloc::{
+    borrow::Cow,
+    string::ToString,
+    vec::Vec,
+};
+use core::fmt::{
+    self,
+    Write as _,
+};
+
+use crate::{
+    BizCode,
+    BizError,
+    BizErrors,
+    BizFieldValue,
+    Chain,
+    ChainLink,
+    ContextFrame,
+    ContextualError,
+    DynBizError,
+    REDACTED,
+};
+
+/// One business error as seen by a [`ReportStyle`]
+pub struct ReportEntry<'a> {
+    error:  &'a dyn DynBizError,
+    frames: Vec<&'a ContextFrame>,
+    causes: Vec<ChainLink<'a>>,
+}
+
+impl<'a> ReportEntry<'a> {
+    pub(crate) fn new<E: BizError>(error: &'a ContextualError<E>) -> Self {
+        Self {
+            error:  error.inner(),
+            // `BizErrors::push_simple` records an empty context
+            frames: error
+                .frames()
+                .filter(|frame| !frame.message().is_empty())
+                .collect(),
+            // Skip the contextual wrapper and the business error itself
+            causes: error.chain().skip(2).collect(),
+        }
+    }
+
+    fn without_context(error: &'a dyn DynBizError) -> Self {
+        Self {
+            error,
+            frames: Vec::new(),
+            // Skip the business error itself
+            causes: Chain::new(error, |source| {
+                #[cfg(feature = "std")]
+                if let Some(biz) = crate::as_biz_error(source) {
+                    return Some(biz);
+                }
+                let _ = source;
+                None
+            })
+            .skip(1)
+            .collect(),
+        }
+    }
+
+    /// Get the business error
+    pub fn biz_error(&self) -> &'a dyn DynBizError {
+        self.error
+    }
+
+    /// Get the business error code
+    pub fn code(&self) -> BizCode {
+        self.error.biz_code()
+    }
+
+    /// Get the business error name
+    pub fn name(&self) -> &'a str {
+        self.error.biz_name()
+    }
+
+    /// Get the structured fields, sensitive values redacted
+    pub fn fields(&self) -> Vec<(Cow<'static, str>, BizFieldValue)> {
+        self.error.biz_fields()
+    }
+
+    /// Get the error message, redacted for sensitive errors
+    pub fn message(&self) -> Cow<'a, str> {
+        if self.error.biz_is_sensitive() {
+            Cow::Borrowed(REDACTED)
+        } else {
+            Cow::Owned(self.error.to_string())
+        }
+    }
+
+    /// Get the context frames, innermost first
+    pub fn frames(&self) -> &[&'a ContextFrame] {
+        &self.frames
+    }
+
+    /// Get the source chain below the business error, outermost first
+    pub fn causes(&self) -> &[ChainLink<'a>] {
+        &self.causes
+    }
+}
+
+/// Errors that a [`Report`] can render
+pub trait Reportable {
+    /// Get one entry per contained business error
+    fn report_entries(&self) -> Vec<ReportEntry<'_>>;
+}
+
+impl<E: BizError> Reportable for ContextualError<E> {
+    fn report_entries(&self) -> Vec<ReportEntry<'_>> {
+        Vec::from([ReportEntry::new(self)])
+    }
+}
+
+impl<E: BizError> Reportable for BizErrors<E> {
+    fn report_entries(&self) -> Vec<ReportEntry<'_>> {
+        self.iter().map(ReportEntry::new).collect()
+    }
+}
+
+/// A business error without context, e.g. `&error as &dyn DynBizError`
+///
+/// A boxed [`ContextualError`] keeps its context frames.
+impl Reportable for dyn DynBizError {
+    fn report_entries(&self) -> Vec<ReportEntry<'_>> {
+        Vec::from([self
+            .biz_report_entry()
+            .unwrap_or_else(|| ReportEntry::without_context(self))])
+    }
+}
+
+/// Output style of a [`Report`]
+///
+/// Implement this trait for custom formats; [`Compact`], [`Tree`] and
+/// [`Logfmt`] are provided.
+pub trait ReportStyle {
+    /// Render the entries of a report
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the formatter fails.
+    fn render(
+        &self,
+        entries: &[ReportEntry<'_>],
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result;
+}
+
+/// Renders an error with a [`ReportStyle`] through `Display`
+///
+/// # Example
+///
+/// ```rust
+/// use bizerror::*;
+///
+/// #[derive(BizError, thiserror::Error)]
+/// pub enum ApiError {
+///     #[bizcode(4004)]
+///     #[error("User not found")]
+///     UserNotFound,
+/// }
+///
+/// let error = ApiError::UserNotFound
+///     .with_context("Loading profile")
+///     .add_context("Serving request");
+///
+/// let line = error.report().to_string();
+/// assert!(line.starts_with(
+///     "UserNotFound[4004]: User not found (context: Loading profile -> \
+///      Serving request, at "
+/// ));
+///
+/// let logfmt = error.report().style(Logfmt).to_string();
+/// assert!(
+///     logfmt.starts_with(
+///         "code=4004 name=UserNotFound message=\"User not found\""
+///     )
+/// );
+/// ```
+pub struct Report<'a, R: ?Sized, S = Compact> {
+    error: &'a R,
+    style: S,
+}
+
+impl<'a, R: Reportable + ?Sized> Report<'a, R> {
+    /// Create a report in the [`Compact`] style
+    pub const fn new(error: &'a R) -> Self {
+        Self {
+            error,
+            style: Compact,
+        }
+    }
+}
+
+impl<'a, R: Reportable + ?Sized, S: ReportStyle> Report<'a, R, S> {
+    /// Render with a different style
+    pub fn style<T: ReportStyle>(self, style: T) -> Report<'a, R, T> {
+        Report {
+            error: self.error,
+            style,
+        }
+    }
+}
+
+impl<R: Reportable + ?Sized, S: ReportStyle> fmt::Display for Report<'_, R, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.style.render(&self.error.report_entries(), f)
+    }
+}
+
+impl<R: Reportable + ?Sized, S: ReportStyle> fmt::Debug for Report<'_, R, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Single-line style for log pipelines
+///
+/// `Name[code]: message (context: a -> b, at file:line:col) <- cause <- ...`,
+/// with multiple errors separated by ` | `.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Compact;
+
+impl ReportStyle for Compact {
+    fn render(
+        &self,
+        entries: &[ReportEntry<'_>],
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        if entries.len() > 1 {
+            write!(f, "{} errors: ", entries.len())?;
+        }
+        for (i, entry) in entries.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" | ")?;
+            }
+            write!(
+                f,
+                "{}[{}]: {}",
+                entry.name(),
+                entry.code(),
+                single_line(&entry.message())
+            )?;
+            if let Some(outermost) = entry.frames.last() {
+                f.write_str(" (context: ")?;
+                for (i, frame) in entry.frames.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" -> ")?;
+                    }
+                    f.write_str(&single_line(frame.message()))?;
+                }
+                write!(f, ", at {})", outermost.location())?;
+            }
+            for cause in &entry.causes {
+                write!(f, " <- {}", single_line(&cause.message()))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Indented multi-line style for terminals
+///
+/// Lists every context frame with its location and the full source chain.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Tree;
+
+impl ReportStyle for Tree {
+    fn render(
+        &self,
+        entries: &[ReportEntry<'_>],
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let indent = if entries.len() > 1 {
+            writeln!(f, "{} errors:", entries.len())?;
+            "  "
+        } else {
+            ""
+        };
+        for (i, entry) in entries.iter().enumerate() {
+            if entries.len() > 1 {
+                write!(f, "{indent}{}. ", i + 1)?;
+            }
+            writeln!(
+                f,
+                "{}[{}]: {}",
+                entry.name(),
+                entry.code(),
+                entry.message()
+            )?;
+            if !entry.frames.is_empty() {
+                writeln!(f, "{indent}  context:")?;
+                for (i, frame) in entry.frames.iter().enumerate() {
+                    writeln!(f, "{indent}    {i}: {frame:#}")?;
+                }
+            }
+            if !entry.causes.is_empty() {
+                writeln!(f, "{indent}  caused by:")?;
+                for (i, cause) in entry.causes.iter().enumerate() {
+                    write!(f, "{indent}    {i}: ")?;
+                    if let (Some(name), Some(code)) =
+                        (cause.name(), cause.code())
+                    {
+                        write!(f, "{name}[{code}]: ")?;
+                    }
+                    writeln!(f, "{}", single_line(&cause.message()))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// [logfmt](https://brandur.org/logfmt) style, one line per error
+///
+/// Keys are `code`, `name`, `message`, `context`, `location` and `causes`;
+/// multiple errors add `index` and `total`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Logfmt;
+
+impl ReportStyle for Logfmt {
+    fn render(
+        &self,
+        entries: &[ReportEntry<'_>],
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        for (i, entry) in entries.iter().enumerate() {
+            if i > 0 {
+                f.write_char('\n')?;
+            }
+            if entries.len() > 1 {
+                write!(f, "index={i} total={} ", entries.len())?;
+            }
+            write!(f, "code=")?;
+            logfmt_value(f, &entry.code().to_string())?;
+            write!(f, " name=")?;
+            logfmt_value(f, entry.name())?;
+            write!(f, " message=")?;
+            logfmt_value(f, &entry.message())?;
+            if let Some(outermost) = entry.frames.last() {
+                let context: Vec<_> =
+                    entry.frames.iter().map(|frame| frame.message()).collect();
+                write!(f, " context=")?;
+                logfmt_value(f, &context.join(" -> "))?;
+                write!(f, " location=")?;
+                logfmt_value(f, &outermost.location().to_string())?;
+            }
+            if !entry.causes.is_empty() {
+                let causes: Vec<_> =
+                    entry.causes.iter().map(ChainLink::message).collect();
+                write!(f, " causes=")?;
+                logfmt_value(f, &causes.join("; "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Write a logfmt value, quoting and escaping it when needed
+fn logfmt_value(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    let needs_quotes = value.is_empty() ||
+        value.chars().any(|c| {
+            c.is_whitespace() || c.is_control() || c == '"' || c == '='
+        });
+    if !needs_quotes {
+        return f.write_str(value);
+    }
+    f.write_char('"')?;
+    for c in value.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+/// Keep multi-line messages on one line
+fn single_line(message: &str) -> Cow<'_, str> {
+    if message.contains(['\n', '\r']) {
+        Cow::Owned(message.replace("\r\n", " ").replace(['\n', '\r'], " "))
+    } else {
+        Cow::Borrowed(message)
+    }
+}
+
+impl<E: BizError> ContextualError<E> {
+    /// Render this error through a [`Report`], in the [`Compact`] style
+    /// unless changed with [`Report::style`]
+    pub const fn report(&self) -> Report<'_, Self> {
+        Report::new(self)
+    }
+}
+
+impl<E: BizError> BizErrors<E> {
+    /// Render these errors through a [`Report`], in the [`Compact`] style
+    /// unless changed with [`Report::style`]
+    pub const fn report(&self) -> Report<'_, Self> {
+        Report::new(self)
+    }
+}
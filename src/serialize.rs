@@ -0,0 +1,381 @@
+//! `serde` serialization of business errors, behind the `serde` feature
+//!
+//! The layout is part of the public API: a business error serializes as
+//! `{code, name, message, fields}`, plus `sensitive: true` for sensitive
+//! errors, a [`ContextualError`] adds `context`, `location` and `sources`,
+//! and [`BizErrors`] serializes as `{count, errors}`.
+
+use alloc::{
+    borrow::Cow,
+    string::{
+        String,
+        ToString,
+    },
+};
+use core::fmt;
+
+use serde::{
+    de::{
+        self,
+        Deserialize,
+        Deserializer,
+        Visitor,
+    },
+    ser::{
+        Serialize,
+        SerializeMap,
+        SerializeSeq,
+        SerializeStruct,
+        Serializer,
+    },
+};
+
+use crate::{
+    BizCode,
+    BizError,
+    BizErrors,
+    BizFieldValue,
+    ChainLink,
+    ContextFrame,
+    ContextualError,
+    DynBizError,
+    REDACTED,
+};
+
+impl Serialize for BizCode {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self {
+            // Prefer 64-bit integers, which every data format supports
+            Self::Int(code) => {
+                if let Ok(code) = i64::try_from(*code) {
+                    serializer.serialize_i64(code)
+                } else if let Ok(code) = u64::try_from(*code) {
+                    serializer.serialize_u64(code)
+                } else {
+                    serializer.serialize_i128(*code)
+                }
+            }
+            Self::Str(code) => serializer.serialize_str(code),
+        }
+    }
+}
+
+/// Accepts any integer or string
+impl<'de> Deserialize<'de> for BizCode {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        struct CodeVisitor;
+
+        impl Visitor<'_> for CodeVisitor {
+            type Value = BizCode;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an integer or string business code")
+            }
+
+            fn visit_i64<E: de::Error>(self, code: i64) -> Result<BizCode, E> {
+                Ok(code.into())
+            }
+
+            fn visit_u64<E: de::Error>(self, code: u64) -> Result<BizCode, E> {
+                Ok(code.into())
+            }
+
+            fn visit_i128<E: de::Error>(
+                self,
+                code: i128,
+            ) -> Result<BizCode, E> {
+                Ok(code.into())
+            }
+
+            fn visit_u128<E: de::Error>(
+                self,
+                code: u128,
+            ) -> Result<BizCode, E> {
+                i128::try_from(code).ok().map(BizCode::Int).ok_or_else(|| {
+                    E::invalid_value(de::Unexpected::Other("u128"), &self)
+                })
+            }
+
+            fn visit_str<E: de::Error>(self, code: &str) -> Result<BizCode, E> {
+                Ok(BizCode::from(String::from(code)))
+            }
+
+            fn visit_string<E: de::Error>(
+                self,
+                code: String,
+            ) -> Result<BizCode, E> {
+                Ok(code.into())
+            }
+        }
+
+        deserializer.deserialize_any(CodeVisitor)
+    }
+}
+
+impl Serialize for BizFieldValue {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Bool(value) => serializer.serialize_bool(*value),
+            Self::Int(value) => serializer.serialize_i64(*value),
+            Self::UInt(value) => serializer.serialize_u64(*value),
+            Self::Float(value) => serializer.serialize_f64(*value),
+            Self::Str(value) => serializer.serialize_str(value),
+            Self::Redacted => serializer.serialize_str(REDACTED),
+        }
+    }
+}
+
+/// The string `<redacted>` is read back as [`BizFieldValue::Redacted`]
+impl<'de> Deserialize<'de> for BizFieldValue {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        struct ValueVisitor;
+
+        impl Visitor<'_> for ValueVisitor {
+            type Value = BizFieldValue;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a boolean, number or string field value")
+            }
+
+            fn visit_bool<E: de::Error>(
+                self,
+                value: bool,
+            ) -> Result<BizFieldValue, E> {
+                Ok(BizFieldValue::Bool(value))
+            }
+
+            fn visit_i64<E: de::Error>(
+                self,
+                value: i64,
+            ) -> Result<BizFieldValue, E> {
+                Ok(BizFieldValue::Int(value))
+            }
+
+            fn visit_u64<E: de::Error>(
+                self,
+                value: u64,
+            ) -> Result<BizFieldValue, E> {
+                Ok(BizFieldValue::UInt(value))
+            }
+
+            fn visit_f64<E: de::Error>(
+                self,
+                value: f64,
+            ) -> Result<BizFieldValue, E> {
+                Ok(BizFieldValue::Float(value))
+            }
+
+            fn visit_str<E: de::Error>(
+                self,
+                value: &str,
+            ) -> Result<BizFieldValue, E> {
+                Ok(if value == REDACTED {
+                    BizFieldValue::Redacted
+                } else {
+                    BizFieldValue::Str(String::from(value))
+                })
+            }
+        }
+
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+/// Serializes as `{message, location}`, the location being `file:line:col`
+impl Serialize for ContextFrame {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut frame = serializer.serialize_struct("ContextFrame", 2)?;
+        frame.serialize_field("message", self.message())?;
+        frame.serialize_field("location", &self.location().to_string())?;
+        frame.end()
+    }
+}
+
+/// Serializes as `{code, name, message, fields, context, location, sources}`
+///
+/// `context` lists the frames innermost first and `sources` the source chain
+/// below the business error as `{message, code, name, sensitive}`, the code
+/// and name only for recognized business errors. Messages are `<redacted>`
+/// and `sensitive` is `true` for sensitive errors.
+impl<E: BizError> Serialize for ContextualError<E> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut error = serializer.serialize_struct("ContextualError", 8)?;
+        serialize_biz_fields(&mut error, self.inner())?;
+        error.serialize_field("context", &Frames(self))?;
+        error.serialize_field("location", &self.location().to_string())?;
+        error.serialize_field("sources", &Sources(self))?;
+        error.end()
+    }
+}
+
+/// Serializes as `{count, errors}`, each error as a [`ContextualError`]
+impl<E: BizError> Serialize for BizErrors<E> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut errors = serializer.serialize_struct("BizErrors", 2)?;
+        errors.serialize_field("count", &self.len())?;
+        errors.serialize_field("errors", &Errors(self))?;
+        errors.end()
+    }
+}
+
+/// Serialize a business error as `{code, name, message, fields}`
+///
+/// Backs the `Serialize` impl generated by `#[bizconfig(serde)]`.
+pub fn serialize_biz_error<E: BizError, S: Serializer>(
+    error: &E,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_struct("BizError", 5)?;
+    serialize_biz_fields(&mut state, error)?;
+    state.end()
+}
+
+/// Write `code`, `name`, `message`, `fields` and `sensitive` of `error`
+pub(crate) fn serialize_biz_fields<E: BizError, S: SerializeStruct>(
+    state: &mut S,
+    error: &E,
+) -> Result<(), S::Error> {
+    let sensitive = error.is_sensitive();
+    let message = if sensitive {
+        Cow::Borrowed(REDACTED)
+    } else {
+        Cow::Owned(error.to_string())
+    };
+    state.serialize_field("code", &BizCode::of(&error.code()))?;
+    state.serialize_field("name", error.name())?;
+    state.serialize_field("message", &message)?;
+    state.serialize_field("fields", &Fields(&error.fields()))?;
+    serialize_sensitive(state, sensitive)
+}
+
+/// Write a source as `{message, code, name, sensitive}`
+pub(crate) fn serialize_source<S: Serializer>(
+    serializer: S,
+    message: &str,
+    code: Option<&BizCode>,
+    name: Option<&str>,
+    sensitive: bool,
+) -> Result<S::Ok, S::Error> {
+    let mut source = serializer.serialize_struct("Source", 4)?;
+    source.serialize_field("message", message)?;
+    if let (Some(code), Some(name)) = (code, name) {
+        source.serialize_field("code", code)?;
+        source.serialize_field("name", name)?;
+    } else {
+        source.skip_field("code")?;
+        source.skip_field("name")?;
+    }
+    serialize_sensitive(&mut source, sensitive)?;
+    source.end()
+}
+
+/// Write `sensitive: true`, leaving the flag out for other errors
+fn serialize_sensitive<S: SerializeStruct>(
+    state: &mut S,
+    sensitive: bool,
+) -> Result<(), S::Error> {
+    if sensitive {
+        state.serialize_field("sensitive", &true)
+    } else {
+        state.skip_field("sensitive")
+    }
+}
+
+struct Fields<'a>(&'a [(Cow<'static, str>, BizFieldValue)]);
+
+impl Serialize for Fields<'_> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (name, value) in self.0 {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+struct Frames<'a, E: BizError>(&'a ContextualError<E>);
+
+impl<E: BizError> Serialize for Frames<'_, E> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        // `BizErrors::push_simple` records an empty context
+        serializer.collect_seq(
+            self.0.frames().filter(|frame| !frame.message().is_empty()),
+        )
+    }
+}
+
+struct Sources<'a, E: BizError>(&'a ContextualError<E>);
+
+impl<E: BizError> Serialize for Sources<'_, E> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        // Skip the contextual wrapper and the business error itself
+        let links = self.0.chain().skip(2);
+        let mut sources = serializer.serialize_seq(Some(links.len()))?;
+        for link in links {
+            sources.serialize_element(&Source(link))?;
+        }
+        sources.end()
+    }
+}
+
+struct Source<'a>(ChainLink<'a>);
+
+impl Serialize for Source<'_> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        // Forwarded remote causes keep what they were received with
+        #[cfg(feature = "std")]
+        if let Some(cause) = self.0.downcast_ref::<crate::RemoteCause>() {
+            return cause.serialize(serializer);
+        }
+        let link = &self.0;
+        serialize_source(
+            serializer,
+            &link.message(),
+            link.code().as_ref(),
+            link.name(),
+            link.biz_error().is_some_and(DynBizError::biz_is_sensitive),
+        )
+    }
+}
+
+struct Errors<'a, E: BizError>(&'a BizErrors<E>);
+
+impl<E: BizError> Serialize for Errors<'_, E> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter())
+    }
+}
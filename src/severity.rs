@@ -0,0 +1,60 @@
+//! How serious a business error is, set with `severity` in `#[bizconfig]` or
+//! `Cargo.toml` and per variant with `#[bizseverity(...)]`
+
+use core::fmt;
+
+/// The severity of a business error, from least to most serious
+///
+/// Severities are ordered, so `severity >= Severity::Error` selects the
+/// errors worth paging for. Business errors are [`Error`](Self::Error)
+/// unless configured otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use bizerror::*;
+///
+/// #[derive(BizError, thiserror::Error)]
+/// #[bizconfig(severity = "warning")]
+/// pub enum CartError {
+///     #[bizcode(4001)]
+///     #[error("Cart is empty")]
+///     Empty,
+///
+///     #[bizcode(5001)]
+///     #[bizseverity(critical)]
+///     #[error("Cart storage corrupted")]
+///     Corrupted,
+/// }
+///
+/// assert_eq!(CartError::Empty.severity(), Severity::Warning);
+/// assert_eq!(CartError::Corrupted.severity(), Severity::Critical);
+/// assert!(CartError::Corrupted.severity() > Severity::Error);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Severity {
+    Info,
+    Warning,
+    #[default]
+    Error,
+    Critical,
+}
+
+impl Severity {
+    /// Get the name as written in `#[bizseverity(...)]`, e.g. `warning`
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+            Self::Critical => "critical",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -43,6 +43,35 @@ pub enum StringCodeError {
     ServiceUnavailable,
 }
 
+/// A code type whose constructor is not `const`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ServiceCode(u16);
+
+impl ServiceCode {
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn new(code: u16) -> Self {
+        Self(code)
+    }
+}
+
+impl std::fmt::Display for ServiceCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SVC-{}", self.0)
+    }
+}
+
+#[derive(BizError, ThisError)]
+#[bizconfig(code_type = "ServiceCode")]
+pub enum CustomCodeError {
+    #[bizcode(ServiceCode::new(7))]
+    #[error("Service degraded")]
+    Degraded,
+
+    #[bizcode(ServiceCode::new(9))]
+    #[error("Service down")]
+    Down,
+}
+
 #[derive(BizError, ThisError)]
 #[bizconfig(code_type = "i32", auto_start = -100, auto_increment = -5)]
 pub enum SignedCodeError {
@@ -57,8 +86,145 @@ pub enum SignedCodeError {
     AnotherNegative,
 }
 
+#[derive(BizError, ThisError)]
+#[bizconfig(code_type = "u8", auto_start = 250, auto_increment = 5)]
+pub enum NarrowCodeError {
+    #[error("First")]
+    First,
+
+    #[error("Last")]
+    Last,
+}
+
+#[derive(BizError, ThisError)]
+#[bizconfig(code_type = "u64", auto_start = 18000000000000000000)]
+pub enum WideCodeError {
+    #[error("Wide")]
+    Wide,
+}
+
+#[derive(BizError, ThisError)]
+pub enum FieldError {
+    #[bizcode(100)]
+    #[error("Upstream {service} returned {status}")]
+    Upstream {
+        service: &'static str,
+        status:  u16,
+        retry:   bool,
+        headers: Vec<String>,
+    },
+
+    #[bizcode(101)]
+    #[error("Invalid ratio {0} for {1}")]
+    InvalidRatio(f64, String),
+
+    #[bizcode(102)]
+    #[error("Wrapped failure: {message}")]
+    Wrapped {
+        message: String,
+        #[source]
+        cause:   io::Error,
+    },
+
+    #[bizcode(103)]
+    #[error("IO failure")]
+    Io(#[from] io::Error),
+}
+
+#[derive(BizError, ThisError)]
+pub enum SensitiveError {
+    #[bizcode(200)]
+    #[error("Invalid value {value} for {field}")]
+    InvalidValue {
+        field: String,
+        #[biz(sensitive)]
+        value: String,
+    },
+
+    #[bizcode(201)]
+    #[error("Token {0} rejected after {1} attempts")]
+    TokenRejected(Redacted<String>, u8),
+}
+
+#[derive(BizError, ThisError)]
+#[bizconfig(auto_start = 100)]
+pub enum UserServiceError {
+    /// No user matches the given identifier.
+    #[error("User not found")]
+    UserNotFound,
+
+    /// The account was suspended by an administrator.
+    ///
+    /// Suspended users can't sign in until reinstated.
+    #[error("User {0} is suspended")]
+    Suspended(u32),
+
+    #[error("User store unavailable")]
+    StoreUnavailable { retry_after: u32 },
+}
+
+#[derive(BizError, ThisError)]
+#[bizmap(from = UserServiceError, UserNotFound => NotFound, Suspended => Forbidden, _ => Internal)]
+pub enum GatewayError {
+    #[bizcode(404)]
+    #[error("Not found")]
+    NotFound(#[source] UserServiceError),
+
+    #[bizcode(403)]
+    #[error("Forbidden")]
+    Forbidden {
+        #[source]
+        source: UserServiceError,
+    },
+
+    #[bizcode(500)]
+    #[error("Internal error")]
+    Internal(#[source] Box<dyn StdError + Send + Sync>),
+}
+
+#[derive(Debug, ThisError)]
+pub enum Upstream<T: std::fmt::Debug> {
+    #[error("Upstream timed out")]
+    Timeout,
+
+    #[error("Upstream rejected {0:?}")]
+    Rejected(T),
+}
+
+#[derive(BizError, ThisError)]
+#[bizmap(from = Upstream<u32>, Timeout => Unavailable, _ => Failed)]
+pub enum ProxyError {
+    #[bizcode(503)]
+    #[error("Upstream unavailable")]
+    Unavailable(#[source] Upstream<u32>),
+
+    #[bizcode(502)]
+    #[error("Upstream failed")]
+    Failed(#[source] Upstream<u32>),
+}
+
 // --- Custom Error for BizError Trait Test ---
 
+#[derive(BizError, ThisError)]
+#[bizconfig(
+    auto_start = 7000,
+    name_case = "snake_case",
+    doc_url = "https://errors.example.com/{code}#{name}",
+    severity = "warning"
+)]
+pub enum CatalogError {
+    #[error("Product not found")]
+    ProductNotFound,
+
+    #[bizcode(7100)]
+    #[bizseverity(critical)]
+    #[error("Catalog index corrupted")]
+    IndexCorrupted,
+
+    #[error("Unknown SKU {0}")]
+    UnknownSKU(String),
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct CustomBizError {
     code:    u32,
@@ -155,6 +321,14 @@ mod tests {
         assert_eq!(err.name(), "AnotherNegative");
     }
 
+    #[test]
+    fn test_auto_codes_use_full_type_range() {
+        assert_eq!(NarrowCodeError::First.code(), 250u8);
+        assert_eq!(NarrowCodeError::Last.code(), 255u8);
+        assert_eq!(WideCodeError::Wide.code(), 18_000_000_000_000_000_000u64);
+        assert!(WideCodeError::WIDE_CODE > i64::MAX as u64);
+    }
+
     #[test]
     fn test_custom_biz_error_trait_impl() {
         let custom_err = CustomBizError {
@@ -170,6 +344,219 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_associated_code_constants() {
+        const UNKNOWN: u32 = AppError::Unknown.code_of_kind();
+        assert_eq!(UNKNOWN, AppError::Unknown.code());
+
+        assert_eq!(AppError::USER_NOT_FOUND_CODE, 1000);
+        assert_eq!(AppError::INVALID_INPUT_CODE, 2001);
+        assert_eq!(AppError::DATABASE_ERROR_CODE, 1010);
+        assert_eq!(AppError::PERMISSION_DENIED_CODE, 3000);
+        assert_eq!(StringCodeError::AUTH_FAILED_CODE, "AUTH_FAILED");
+        assert_eq!(SignedCodeError::ANOTHER_NEGATIVE_CODE, -105);
+
+        let describe = |code: u32| match code {
+            AppError::USER_NOT_FOUND_CODE => "user",
+            AppError::PERMISSION_DENIED_CODE => "permission",
+            _ => "other",
+        };
+        assert_eq!(describe(1000), "user");
+        assert_eq!(describe(3000), "permission");
+        assert_eq!(describe(2001), "other");
+
+        let kind = match StringCodeError::AuthFailed.code() {
+            StringCodeError::NOT_FOUND_CODE => "not found",
+            StringCodeError::AUTH_FAILED_CODE => "auth",
+            _ => "other",
+        };
+        assert_eq!(kind, "auth");
+    }
+
+    #[test]
+    fn test_custom_code_type() {
+        assert_eq!(CustomCodeError::Degraded.code(), ServiceCode::new(7));
+        assert_eq!(CustomCodeError::Down.code().to_string(), "SVC-9");
+        let dynamic: &dyn DynBizError = &CustomCodeError::Down;
+        assert_eq!(dynamic.code(), BizCode::Str("SVC-9".into()));
+    }
+
+    #[test]
+    fn test_fields_extraction() {
+        let err = FieldError::Upstream {
+            service: "billing",
+            status:  503,
+            retry:   true,
+            headers: vec!["x-request-id".to_string()],
+        };
+        // `headers` has no `ToBizFieldValue` impl and is skipped
+        assert_eq!(err.fields(), vec![
+            ("service".into(), BizFieldValue::Str("billing".to_string())),
+            ("status".into(), BizFieldValue::UInt(503)),
+            ("retry".into(), BizFieldValue::Bool(true)),
+        ]);
+
+        let err = FieldError::InvalidRatio(0.5, "discount".to_string());
+        assert_eq!(err.fields(), vec![
+            ("0".into(), BizFieldValue::Float(0.5)),
+            ("1".into(), BizFieldValue::Str("discount".to_string())),
+        ]);
+
+        // Source fields are never exposed
+        let err = FieldError::Wrapped {
+            message: "sync failed".to_string(),
+            cause:   io::Error::other("disk full"),
+        };
+        assert_eq!(err.fields(), vec![(
+            "message".into(),
+            BizFieldValue::Str("sync failed".to_string())
+        )]);
+        assert!(FieldError::from(io::Error::other("x")).fields().is_empty());
+
+        // Unit variants and contextual wrappers
+        assert!(AppError::PermissionDenied.fields().is_empty());
+        let contextual =
+            AppError::UserNotFound { user_id: 7 }.with_context("Loading user");
+        assert_eq!(contextual.fields(), vec![(
+            "user_id".into(),
+            BizFieldValue::UInt(7)
+        )]);
+        assert_eq!(BizFieldValue::Int(-3).to_string(), "-3");
+    }
+
+    #[test]
+    fn test_sensitive_fields_are_redacted() {
+        let err = SensitiveError::InvalidValue {
+            field: "password".to_string(),
+            value: "hunter2".to_string(),
+        };
+        assert_eq!(err.fields(), vec![
+            ("field".into(), BizFieldValue::Str("password".to_string())),
+            ("value".into(), BizFieldValue::Redacted),
+        ]);
+        let debug_str = format!("{err:?}");
+        assert!(debug_str.contains("message: \"<redacted>\""));
+        assert!(!debug_str.contains("hunter2"));
+
+        let contextual = err.with_context("Validating login form");
+        assert!(!format!("{contextual:?}").contains("hunter2"));
+
+        let token = Redacted::new("secret-token".to_string());
+        let err = SensitiveError::TokenRejected(token, 3);
+        assert_eq!(
+            err.to_string(),
+            "Token <redacted> rejected after 3 attempts"
+        );
+        assert!(!format!("{err:?}").contains("secret-token"));
+        assert_eq!(err.fields(), vec![
+            ("0".into(), BizFieldValue::Redacted),
+            ("1".into(), BizFieldValue::UInt(3))
+        ]);
+        if let SensitiveError::TokenRejected(token, _) = err {
+            assert_eq!(token.reveal(), "secret-token");
+            assert_eq!(format!("{token:?}"), "<redacted>");
+        }
+    }
+
+    #[test]
+    fn test_bizmap_cross_enum_conversion() {
+        let err = GatewayError::from(UserServiceError::UserNotFound);
+        assert_eq!(err.code(), 404);
+        assert_eq!(err.name(), "NotFound");
+        let source = err.source().expect("source is kept");
+        assert_eq!(source.to_string(), "User not found");
+        assert!(source.downcast_ref::<UserServiceError>().is_some());
+
+        let err: GatewayError = UserServiceError::Suspended(7).into();
+        assert_eq!(err.code(), 403);
+        assert_eq!(err.source().expect("").to_string(), "User 7 is suspended");
+
+        let err = GatewayError::from(UserServiceError::StoreUnavailable {
+            retry_after: 30,
+        });
+        assert_eq!(err.code(), 500);
+        assert_eq!(err.name(), "Internal");
+        assert_eq!(
+            err.source().expect("").to_string(),
+            "User store unavailable"
+        );
+
+        // Works with `?` and the Result extensions
+        let result: Result<(), UserServiceError> =
+            Err(UserServiceError::UserNotFound);
+        let contextual: Result<(), ContextualError<GatewayError>> =
+            result.with_context("Loading profile");
+        assert_eq!(contextual.expect_err("").code(), 404);
+
+        // Generic source types
+        assert_eq!(ProxyError::from(Upstream::Timeout).code(), 503);
+        let err = ProxyError::from(Upstream::Rejected(7));
+        assert_eq!(err.name(), "Failed");
+        assert_eq!(err.source().expect("").to_string(), "Upstream rejected 7");
+    }
+
+    #[test]
+    fn test_description_from_doc_comments() {
+        // `std::error::Error::description` is in scope too, so disambiguate
+        assert_eq!(
+            BizError::description(&UserServiceError::UserNotFound),
+            Some("No user matches the given identifier.")
+        );
+        assert_eq!(
+            BizError::description(&UserServiceError::Suspended(1)),
+            Some(
+                "The account was suspended by an administrator.\n\nSuspended \
+                 users can't sign in until reinstated."
+            )
+        );
+        assert_eq!(
+            BizError::description(&UserServiceError::StoreUnavailable {
+                retry_after: 1,
+            }),
+            None
+        );
+        assert_eq!(BizError::description(&AppError::Unknown), None);
+
+        let contextual = UserServiceError::UserNotFound.with_context("Login");
+        assert_eq!(
+            BizError::description(&contextual),
+            Some("No user matches the given identifier.")
+        );
+    }
+
+    #[test]
+    fn test_name_case_doc_url_and_severity() {
+        assert_eq!(CatalogError::ProductNotFound.name(), "product_not_found");
+        assert_eq!(
+            CatalogError::UnknownSKU("X1".to_string()).name(),
+            "unknown_sku"
+        );
+        assert_eq!(
+            CatalogError::IndexCorrupted.doc_url(),
+            Some("https://errors.example.com/7100#index_corrupted")
+        );
+        assert_eq!(
+            CatalogError::UnknownSKU("X1".to_string()).doc_url(),
+            Some("https://errors.example.com/7001#unknown_sku")
+        );
+        assert_eq!(CatalogError::ProductNotFound.severity(), Severity::Warning);
+        assert_eq!(CatalogError::IndexCorrupted.severity(), Severity::Critical);
+
+        // Without configuration
+        assert_eq!(AppError::Unknown.name(), "Unknown");
+        assert_eq!(AppError::Unknown.doc_url(), None);
+        assert_eq!(AppError::Unknown.severity(), Severity::Error);
+
+        let contextual =
+            CatalogError::IndexCorrupted.with_context("Reindexing");
+        assert_eq!(contextual.severity(), Severity::Critical);
+        let dynamic: &dyn DynBizError = &contextual;
+        assert_eq!(
+            dynamic.biz_doc_url(),
+            Some("https://errors.example.com/7100#index_corrupted")
+        );
+    }
+
     // --- ContextualError Struct Tests ---
 
     #[test]
@@ -199,6 +586,114 @@ mod tests {
         assert_eq!(layered.code(), 2001);
     }
 
+    #[test]
+    fn test_contextual_error_frames() {
+        let inner = AppError::PermissionDenied.with_context("Checking ACL");
+        let inner_line = line!() - 1;
+        let layered = inner.add_context("Serving request");
+        let outer_line = line!() - 1;
+
+        let frames: Vec<_> = layered.frames().collect();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].message(), "Checking ACL");
+        assert_eq!(frames[0].location().line(), inner_line);
+        assert_eq!(frames[1].message(), "Serving request");
+        assert_eq!(frames[1].location().line(), outer_line);
+        assert_eq!(layered.location().line(), outer_line);
+
+        assert!(
+            layered
+                .to_string()
+                .ends_with("Context: Checking ACL -> Serving request")
+        );
+        let detailed = format!("{layered:#}");
+        assert!(detailed.contains(&format!(
+            "\n  Checking ACL, at {}",
+            frames[0].location()
+        )));
+        assert!(detailed.contains(&format!(
+            "\n  Serving request, at {}",
+            frames[1].location()
+        )));
+    }
+
+    #[test]
+    fn test_static_and_lazy_context() {
+        let static_ctx =
+            AppError::PermissionDenied.with_static_context("Checking ACL");
+        assert_eq!(static_ctx.context(), "Checking ACL");
+        assert!(static_ctx.location().file().contains("bizerror_tests.rs"));
+
+        let layered = static_ctx.add_static_context("Serving request");
+        assert_eq!(layered.context(), "Checking ACL -> Serving request");
+
+        let mut evaluated = false;
+        let ok: Result<u32, ContextualError<AppError>> =
+            Ok::<u32, io::Error>(1).with_context_lazy(|| {
+                evaluated = true;
+                "unused"
+            });
+        assert_eq!(ok.expect(""), 1);
+        assert!(!evaluated);
+
+        let id = 9;
+        let err: ContextualError<AppError> =
+            Err::<(), _>(io::Error::other("disk full"))
+                .with_context_lazy(|| format!("Saving user {id}"))
+                .expect_err("");
+        assert_eq!(err.context(), "Saving user 9");
+        assert!(err.location().file().contains("bizerror_tests.rs"));
+
+        let none: Option<u32> = None;
+        let err = none
+            .with_context_lazy(AppError::PermissionDenied, || {
+                format!("Resolving owner of {id}")
+            })
+            .expect_err("");
+        assert_eq!(err.context(), "Resolving owner of 9");
+        assert_eq!(
+            AppError::PermissionDenied
+                .with_context_lazy(|| "Lazily")
+                .context(),
+            "Lazily"
+        );
+    }
+
+    #[cfg(feature = "backtrace")]
+    #[test]
+    fn test_backtrace_policy() {
+        use std::backtrace::{
+            Backtrace,
+            BacktraceStatus,
+        };
+
+        let enabled =
+            Backtrace::capture().status() == BacktraceStatus::Captured;
+
+        set_backtrace_policy(Some(|request| {
+            request.code::<u32>() == Some(&3000) &&
+                request.name() == "PermissionDenied"
+        }));
+        let selected = AppError::PermissionDenied.with_context("Selected");
+        let skipped =
+            AppError::UserNotFound { user_id: 1 }.with_context("Skipped");
+        set_backtrace_policy(None);
+
+        assert_eq!(selected.backtrace().is_some(), enabled);
+        assert!(skipped.backtrace().is_none());
+        assert_eq!(format!("{selected:?}").contains("backtrace:"), enabled);
+
+        set_backtrace_policy(Some(|request| {
+            request.severity() >= Severity::Critical
+        }));
+        let critical = CatalogError::IndexCorrupted.with_context("Reindexing");
+        let warning = CatalogError::ProductNotFound.with_context("Searching");
+        set_backtrace_policy(None);
+
+        assert_eq!(critical.backtrace().is_some(), enabled);
+        assert!(warning.backtrace().is_none());
+    }
+
     #[test]
     fn test_contextual_error_into_inner() {
         let err = AppError::PermissionDenied;
@@ -208,6 +703,13 @@ mod tests {
 
     #[test]
     fn test_contextual_error_debug_display() {
+        // `location()` stays usable in const contexts
+        const fn outermost(
+            error: &ContextualError<AppError>,
+        ) -> &'static std::panic::Location<'static> {
+            error.location()
+        }
+
         let io_err = io::Error::new(io::ErrorKind::BrokenPipe, "pipe broken");
         let err = AppError::from(io_err);
         let contextual = err.with_context("Writing to socket");
@@ -218,9 +720,17 @@ mod tests {
         assert!(debug_str.contains("type: \"DatabaseError\""));
         assert!(debug_str.contains("code: 1010"));
         assert!(debug_str.contains("context: \"Writing to socket\""));
+        assert!(debug_str.contains("location: \"tests/bizerror_tests.rs:"));
+
+        let layered = AppError::PermissionDenied
+            .with_context("Reading file")
+            .add_context("Loading profile");
+        let context: &str = layered.context();
+        assert_eq!(context, "Reading file -> Loading profile");
+        assert_eq!(outermost(&layered).line(), line!() - 3);
         assert!(
-            debug_str.contains("location:") &&
-                debug_str.contains("bizerror_tests.rs")
+            format!("{layered:?}")
+                .contains("context: \"Reading file -> Loading profile\"")
         );
 
         let display_str = format!("{contextual}");
@@ -506,4 +1016,719 @@ mod tests {
         assert!(contextual_errors.contains_code(1000));
         assert!(contextual_errors.contains_code(3000));
     }
+
+    #[test]
+    fn test_biz_errors_array_overflow() {
+        let mut errors = BizErrorsArray::<AppError, 2>::new();
+        assert!(errors.is_empty());
+
+        assert!(errors.push_simple(AppError::PermissionDenied));
+        assert!(errors.push_with_context(
+            AppError::UserNotFound { user_id: 7 },
+            "Loading profile"
+        ));
+        assert!(!errors.push_simple(AppError::PermissionDenied));
+
+        assert!(errors.is_full());
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors.overflow(), 1);
+        assert_eq!(errors.total(), 3);
+        assert_eq!(errors.code(), 3000);
+        assert_eq!(errors.last().expect("stored").context(), "Loading profile");
+        assert!(errors.to_string().contains("... and 1 more not recorded"));
+
+        let collected: BizErrorsArray<AppError, 1> =
+            [AppError::PermissionDenied, AppError::PermissionDenied]
+                .into_iter()
+                .collect();
+        assert_eq!(collected.iter().count(), 1);
+        assert_eq!(collected.overflow(), 1);
+    }
+
+    #[test]
+    fn test_static_contextual_error() {
+        let error = StaticContextualError::new(
+            AppError::UserNotFound { user_id: 42 },
+            "Resolving owner",
+        );
+        let line = line!() - 4;
+
+        assert_eq!(error.code(), 1000);
+        assert_eq!(error.context(), "Resolving owner");
+        assert_eq!(error.location().line(), line);
+        assert!(format!("{error:?}").contains("StaticContextualError"));
+
+        let contextual: ContextualError<AppError> = error.into();
+        assert_eq!(contextual.context(), "Resolving owner");
+        assert_eq!(contextual.location().line(), line);
+    }
+
+    #[test]
+    fn test_debug_message_is_escaped_like_str() {
+        #[derive(BizError, ThisError)]
+        enum ConnectError {
+            #[error("can't connect to \"{0}\"\n")]
+            Refused(String),
+        }
+
+        let err = ConnectError::Refused("db".to_string());
+        let expected = format!("message: {:?}", err.to_string());
+        assert_eq!(expected, r#"message: "can't connect to \"db\"\n""#);
+        assert!(format!("{err:?}").contains(&expected));
+
+        let fixed = StaticContextualError::new(err, "Connecting");
+        assert!(format!("{fixed:?}").contains(&expected));
+    }
+
+    #[test]
+    fn test_dyn_biz_error() {
+        fn lookup(fail: bool) -> Result<(), Box<dyn DynBizError>> {
+            if fail {
+                Err(AppError::PermissionDenied)?;
+            }
+            Ok(())
+        }
+
+        let errors: Vec<Box<dyn DynBizError>> = vec![
+            AppError::UserNotFound { user_id: 5 }.into(),
+            StringCodeError::AuthFailed.into(),
+            SignedCodeError::NegativeOp.into(),
+            Box::new(AppError::PermissionDenied.with_context("Wrapped")),
+        ];
+
+        assert_eq!(errors[0].code(), BizCode::Int(1000));
+        assert_eq!(errors[0].name(), "UserNotFound");
+        assert!(errors[0].type_name().ends_with("AppError"));
+        assert_eq!(errors[1].code().as_str(), Some("AUTH_FAILED"));
+        assert_eq!(
+            errors[2].code().as_int(),
+            Some(SignedCodeError::NEGATIVE_OP_CODE.into())
+        );
+        assert_eq!(errors[3].code(), BizCode::Int(3000));
+        assert_eq!(
+            errors[3].to_string(),
+            "Permission denied\nContext: Wrapped"
+        );
+
+        assert!(errors[1].is::<StringCodeError>());
+        assert!(errors[1].downcast_ref::<AppError>().is_none());
+
+        let boxed = lookup(true).expect_err("");
+        let boxed = boxed.downcast::<StringCodeError>().expect_err("");
+        let concrete = boxed.downcast::<AppError>().expect("");
+        assert!(matches!(*concrete, AppError::PermissionDenied));
+    }
+
+    #[test]
+    fn test_discover_biz_errors_in_chain() {
+        register_biz_error::<GatewayError>();
+        register_biz_error::<UserServiceError>();
+
+        let error = GatewayError::from(UserServiceError::Suspended(3))
+            .with_context("Proxying request");
+        let found = error.biz_errors();
+        assert_eq!(found.len(), 2);
+        assert!(found[0].0.ends_with("GatewayError"));
+        assert_eq!(
+            (&found[0].1, found[0].2),
+            (&BizCode::Int(403), "Forbidden")
+        );
+        assert!(found[1].0.ends_with("UserServiceError"));
+        assert_eq!(
+            (&found[1].1, found[1].2),
+            (
+                &BizCode::Int(UserServiceError::SUSPENDED_CODE.into()),
+                "Suspended"
+            )
+        );
+
+        // Found through a boxed `dyn Error` source as well
+        let internal = GatewayError::from(UserServiceError::StoreUnavailable {
+            retry_after: 5,
+        });
+        let boxed: Box<dyn StdError + Send + Sync> = Box::new(internal);
+        assert_eq!(
+            boxed.innermost_biz_code(),
+            Some(BizCode::Int(
+                UserServiceError::STORE_UNAVAILABLE_CODE.into()
+            ))
+        );
+
+        let unregistered = io::Error::other("plain io");
+        assert!(unregistered.biz_errors().is_empty());
+        assert_eq!(unregistered.innermost_biz_code(), None);
+    }
+
+    #[test]
+    fn test_convert_contextual_error() {
+        fn repository() -> Result<(), ContextualError<UserServiceError>> {
+            Err(UserServiceError::UserNotFound
+                .with_context("Querying users")
+                .add_context("Loading profile"))
+        }
+
+        let original = repository().expect_err("");
+        let locations: Vec<_> =
+            original.frames().map(ContextFrame::location).collect();
+
+        let converted: ContextualError<GatewayError> = original.convert();
+        assert_eq!(converted.code(), 404);
+        assert_eq!(converted.context(), "Querying users -> Loading profile");
+        assert!(
+            converted
+                .frames()
+                .map(ContextFrame::location)
+                .eq(locations.iter().copied())
+        );
+
+        let mapped = converted.map_inner(|_| AppError::PermissionDenied);
+        assert_eq!(mapped.code(), 3000);
+        assert_eq!(mapped.location(), locations[1]);
+
+        let result: Result<(), ContextualError<GatewayError>> =
+            repository().convert_contextual();
+        let error = result.expect_err("");
+        assert_eq!(error.code(), 404);
+        assert_eq!(error.frames().count(), 2);
+    }
+
+    #[test]
+    fn test_error_chain_iterator() {
+        let err = create_complex_error_chain();
+
+        let links: Vec<_> = err.chain().collect();
+        assert_eq!(links.len(), err.chain_depth());
+        assert_eq!(links[0].code(), Some(BizCode::Int(1010)));
+        assert_eq!(links[1].name(), Some("DatabaseError"));
+        assert!(links[1].downcast_ref::<AppError>().is_some());
+        assert_eq!(links[2].code(), None);
+        assert_eq!(links[2].depth(), 2);
+
+        let root = err.chain().root().expect("root link");
+        assert_eq!(root.message(), err.root_cause_message());
+        assert!(root.downcast_ref::<io::Error>().is_some());
+
+        let innermost_biz = err.chain().rev().find_map(|link| link.name());
+        assert_eq!(innermost_biz, Some("DatabaseError"));
+    }
+
+    #[test]
+    fn test_report_styles() {
+        struct CodesOnly;
+
+        #[derive(BizError, ThisError)]
+        enum ImportError {
+            #[error("Row 3 rejected:\nmissing price")]
+            Rejected,
+        }
+
+        impl ReportStyle for CodesOnly {
+            fn render(
+                &self,
+                entries: &[ReportEntry<'_>],
+                f: &mut std::fmt::Formatter<'_>,
+            ) -> std::fmt::Result {
+                let codes: Vec<_> =
+                    entries.iter().map(|e| e.code().to_string()).collect();
+                write!(f, "{}", codes.join(","))
+            }
+        }
+
+        let err = create_complex_error_chain();
+        let location = err.location();
+
+        let compact = err.report().to_string();
+        assert!(!compact.contains('\n'));
+        assert!(compact.starts_with(
+            "DatabaseError[1010]: Database connection failed (context: \
+             Loading application config -> Initializing services -> \
+             Application startup failed, at "
+        ));
+        assert!(
+            compact.ends_with(&format!("{location}) <- config.toml not found"))
+        );
+
+        let tree = err.report().style(Tree).to_string();
+        assert!(tree.starts_with(
+            "DatabaseError[1010]: Database connection failed\n  context:\n    \
+             0: Loading application config, at "
+        ));
+        assert!(tree.contains(&format!(
+            "    2: Application startup failed, at {location}\n"
+        )));
+        assert!(tree.ends_with("  caused by:\n    0: config.toml not found\n"));
+
+        let logfmt = err.report().style(Logfmt).to_string();
+        assert!(logfmt.starts_with(
+            "code=1010 name=DatabaseError message=\"Database connection \
+             failed\" context=\"Loading application config -> Initializing \
+             services -> Application startup failed\" location="
+        ));
+        assert!(logfmt.ends_with(" causes=\"config.toml not found\""));
+
+        let mut errors = BizErrors::new();
+        errors.push_simple(SensitiveError::InvalidValue {
+            field: "age".to_string(),
+            value: "secret".to_string(),
+        });
+        errors.push_with_context(
+            SensitiveError::TokenRejected(Redacted::new("t0k".to_string()), 3),
+            "Signing in",
+        );
+        let compact = errors.report().to_string();
+        assert!(compact.starts_with(
+            "2 errors: InvalidValue[200]: <redacted> | TokenRejected[201]: \
+             Token <redacted> rejected after 3 attempts (context: Signing in, \
+             at "
+        ));
+        assert!(!compact.contains("secret"));
+        assert!(errors.report().style(Logfmt).to_string().starts_with(
+            "index=0 total=2 code=200 name=InvalidValue \
+             message=<redacted>\nindex=1 total=2 code=201"
+        ));
+        assert_eq!(errors.report().style(CodesOnly).to_string(), "200,201");
+
+        // Multi-line messages and context stay on one line
+        let compact = ImportError::Rejected
+            .with_context("Importing\nprices.csv")
+            .add_context("Nightly sync\r\nbatch 7")
+            .report()
+            .to_string();
+        assert!(!compact.contains(['\n', '\r']), "{compact}");
+        assert!(compact.starts_with(
+            "Rejected[0]: Row 3 rejected: missing price (context: Importing \
+             prices.csv -> Nightly sync batch 7, at "
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_serialization() {
+        use serde_json::json;
+
+        #[derive(BizError, ThisError)]
+        #[bizconfig(serde, code_type = "&'static str")]
+        pub enum LoginError {
+            #[bizcode("LOGIN_LOCKED")]
+            #[error("Account {user} locked")]
+            Locked { user: String, attempts: u8 },
+
+            #[bizcode("LOGIN_BAD_PASSWORD")]
+            #[error("Bad password {0}")]
+            BadPassword(#[biz(sensitive)] String),
+        }
+
+        let locked = LoginError::Locked {
+            user:     "alice".to_string(),
+            attempts: 3,
+        };
+        assert_eq!(
+            serde_json::to_value(&locked).expect("serializable"),
+            json!({
+                "code": "LOGIN_LOCKED",
+                "name": "Locked",
+                "message": "Account alice locked",
+                "fields": { "user": "alice", "attempts": 3 },
+            })
+        );
+        let hidden = serde_json::to_string(&LoginError::BadPassword(
+            "hunter2".to_string(),
+        ))
+        .expect("serializable");
+        assert!(!hidden.contains("hunter2"));
+
+        let err = create_complex_error_chain();
+        let location = err.location().to_string();
+        let value = serde_json::to_value(&err).expect("serializable");
+        assert_eq!(value["code"], 1010);
+        assert_eq!(value["name"], "DatabaseError");
+        assert_eq!(value["message"], "Database connection failed");
+        assert_eq!(value["fields"], json!({}));
+        assert_eq!(
+            value["context"][0]["message"],
+            "Loading application config"
+        );
+        assert_eq!(value["context"][2]["location"], location.as_str());
+        assert_eq!(value["location"], location.as_str());
+        assert_eq!(
+            value["sources"],
+            json!([{ "message": "config.toml not found" }])
+        );
+
+        let mut errors = BizErrors::new();
+        errors.push_simple(SensitiveError::InvalidValue {
+            field: "age".to_string(),
+            value: "secret".to_string(),
+        });
+        let value = serde_json::to_value(&errors).expect("serializable");
+        assert_eq!(value["count"], 1);
+        assert_eq!(value["errors"][0]["message"], "<redacted>");
+        assert_eq!(
+            value["errors"][0]["fields"],
+            json!({ "field": "age", "value": "<redacted>" })
+        );
+        assert_eq!(value["errors"][0]["context"], json!([]));
+    }
+
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[test]
+    fn test_remote_codes_and_fields_are_owned() {
+        use std::{
+            borrow::Cow,
+            sync::Arc,
+        };
+
+        let receive = |index: usize| -> RemoteBizError {
+            serde_json::from_str(&format!(
+                r#"{{"code":"E_{index}","name":"Unique","message":"failed",
+                    "fields":{{"field_{index}":"value"}}}}"#
+            ))
+            .expect("deserializable")
+        };
+
+        for index in 0..1000 {
+            let remote = receive(index);
+            assert_eq!(
+                remote.code().as_str(),
+                Some(format!("E_{index}").as_str())
+            );
+            assert!(remote.field(&format!("field_{index}")).is_some());
+        }
+
+        // Equal codes received twice are separate allocations, and dropping
+        // the error leaves the code to its last holder: no table keeps them
+        let first = receive(7);
+        let again = receive(7);
+        let (RemoteCode::Str(code), RemoteCode::Str(code_again)) =
+            (first.code(), again.code())
+        else {
+            panic!("string codes expected");
+        };
+        assert_eq!(code, code_again);
+        assert!(!Arc::ptr_eq(&code, &code_again));
+        drop(first);
+        assert_eq!(Arc::strong_count(&code), 1);
+        assert!(
+            again
+                .fields()
+                .iter()
+                .all(|(name, _)| matches!(name, Cow::Owned(_)))
+        );
+    }
+
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[test]
+    fn test_remote_biz_error() {
+        #[derive(BizError, ThisError)]
+        #[bizconfig(serde, code_type = "&'static str")]
+        pub enum InventoryError {
+            #[bizcode("OUT_OF_STOCK")]
+            #[error("Out of stock")]
+            OutOfStock,
+
+            #[bizcode("RESERVED")]
+            #[error("Item {sku} reserved")]
+            Reserved { sku: String },
+        }
+
+        // A `ContextualError` round-trips through `RemoteBizError`
+        let err = create_complex_error_chain();
+        let json = serde_json::to_value(&err).expect("serializable");
+        let remote: RemoteBizError =
+            serde_json::from_value(json.clone()).expect("deserializable");
+        assert_eq!(remote.code(), RemoteCode::Int(1010));
+        assert_eq!(remote.name(), "DatabaseError");
+        assert_eq!(remote.to_string(), "Database connection failed");
+        assert_eq!(remote.context().len(), 3);
+        assert_eq!(remote.context()[2].message(), "Application startup failed");
+        assert_eq!(
+            remote.location(),
+            Some(err.location().to_string().as_str())
+        );
+        assert_eq!(remote.sources().collect::<Vec<_>>(), [
+            "config.toml not found"
+        ]);
+        assert_eq!(
+            remote.source().map(ToString::to_string).as_deref(),
+            Some("config.toml not found")
+        );
+        assert_eq!(serde_json::to_value(&remote).expect("serializable"), json);
+
+        // Fields and string codes survive, sensitive values stay redacted
+        let remote: RemoteBizError = serde_json::from_str(
+            &serde_json::to_string(
+                &SensitiveError::InvalidValue {
+                    field: "age".to_string(),
+                    value: "secret".to_string(),
+                }
+                .with_context("Validating profile"),
+            )
+            .expect("serializable"),
+        )
+        .expect("deserializable");
+        assert!(remote.is_sensitive());
+        assert_eq!(remote.fields(), [
+            ("field".into(), BizFieldValue::Str("age".to_string())),
+            ("value".into(), BizFieldValue::Redacted),
+        ]);
+        assert_eq!(remote.field("value"), Some(&BizFieldValue::Redacted));
+
+        // Unit variants are found by code, other variants need a lookup
+        let remote: RemoteBizError = serde_json::from_value(
+            serde_json::to_value(InventoryError::OutOfStock)
+                .expect("serializable"),
+        )
+        .expect("deserializable");
+        assert_eq!(remote.code().as_str(), Some("OUT_OF_STOCK"));
+        assert!(matches!(
+            remote.try_into_typed::<InventoryError>(),
+            Ok(InventoryError::OutOfStock)
+        ));
+
+        let remote: RemoteBizError = serde_json::from_value(
+            serde_json::to_value(InventoryError::Reserved {
+                sku: "A-1".to_string(),
+            })
+            .expect("serializable"),
+        )
+        .expect("deserializable");
+        let remote = remote
+            .try_into_typed::<InventoryError>()
+            .expect_err("no unit variant for RESERVED");
+        let local = remote.try_into_typed_with(|remote| {
+            match (remote.code().as_str(), remote.field("sku")) {
+                (Some("RESERVED"), Some(BizFieldValue::Str(sku))) => {
+                    Some(InventoryError::Reserved { sku: sku.clone() })
+                }
+                _ => None,
+            }
+        });
+        assert!(matches!(
+            local,
+            Ok(InventoryError::Reserved { sku }) if sku == "A-1"
+        ));
+    }
+
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[test]
+    fn test_remote_causes_and_sensitivity() {
+        #[derive(BizError, ThisError)]
+        #[bizconfig(serde, code_type = "&'static str")]
+        pub enum ReservationError {
+            #[bizcode("REJECTED")]
+            #[error("Reservation rejected")]
+            Rejected(#[source] SensitiveError),
+        }
+
+        // The sensitive flag is sent, not guessed from the message
+        let remote: RemoteBizError = serde_json::from_str(
+            r#"{"code":1,"name":"Quoted","message":"<redacted>"}"#,
+        )
+        .expect("deserializable");
+        assert!(!remote.is_sensitive());
+
+        // Business sources keep their code and name, also when forwarded
+        register_biz_error::<SensitiveError>();
+        let err = ReservationError::Rejected(SensitiveError::TokenRejected(
+            Redacted::new("abc".to_string()),
+            3,
+        ))
+        .with_context("Reserving stock");
+        let json = serde_json::to_value(&err).expect("serializable");
+        let remote: RemoteBizError =
+            serde_json::from_value(json.clone()).expect("deserializable");
+        let cause = remote.causes().next().expect("cause");
+        assert_eq!(cause.code(), Some(&RemoteCode::Int(201)));
+        assert_eq!(cause.name(), Some("TokenRejected"));
+        assert!(!cause.is_sensitive());
+        let forwarded = serde_json::to_value(remote.with_context("Forwarding"))
+            .expect("serializable");
+        assert_eq!(forwarded["sources"], json["sources"]);
+
+        // Sources serialized as bare messages are still accepted
+        let remote: RemoteBizError = serde_json::from_str(
+            r#"{"code":1,"name":"Old","message":"failed","sources":["io"]}"#,
+        )
+        .expect("deserializable");
+        let cause = remote.causes().next().expect("cause");
+        assert_eq!(cause.message(), "io");
+        assert_eq!(cause.code(), None);
+    }
+
+    #[test]
+    fn test_problem_details() {
+        let config = ProblemConfig::new()
+            .type_template("https://errors.example.com/{name}/{code}")
+            .status_with(|error| match error.code().as_int() {
+                Some(1000..=1999) => 503,
+                Some(2000..=2999) => 400,
+                _ => 500,
+            });
+
+        let err = create_complex_error_chain();
+        let problem = config.problem(&err);
+        assert_eq!(
+            problem.type_uri(),
+            "https://errors.example.com/DatabaseError/1010"
+        );
+        assert_eq!(problem.title(), "DatabaseError");
+        assert_eq!(problem.status(), 503);
+        assert_eq!(problem.detail(), "Database connection failed");
+        assert_eq!(problem.code(), Some(&BizCode::Int(1010)));
+        assert_eq!(problem.context().len(), 3);
+        assert_eq!(problem.sources(), ["config.toml not found"]);
+        assert!(problem.errors().is_empty());
+
+        let production = config.production().problem(&err);
+        assert!(production.context().is_empty());
+        assert!(production.sources().is_empty());
+        assert_eq!(production.detail(), problem.detail());
+
+        let plain = ProblemConfig::new().biz_problem(&AppError::InvalidInput {
+            field: "email".to_string(),
+        });
+        assert_eq!(plain.type_uri(), "about:blank");
+        assert_eq!(plain.status(), 500);
+        // The doc URL is the type unless a template is configured
+        assert_eq!(
+            ProblemConfig::new()
+                .biz_problem(&CatalogError::ProductNotFound)
+                .type_uri(),
+            "https://errors.example.com/7000#product_not_found"
+        );
+        assert_eq!(
+            config
+                .biz_problem(&CatalogError::ProductNotFound)
+                .type_uri(),
+            "https://errors.example.com/product_not_found/7000"
+        );
+        assert_eq!(plain.fields(), [(
+            "field".into(),
+            BizFieldValue::Str("email".to_string())
+        )]);
+
+        let mut errors = BizErrors::new();
+        errors.push_simple(AppError::InvalidInput {
+            field: "name".to_string(),
+        });
+        errors.push_with_context(
+            AppError::PermissionDenied,
+            "Validating profile",
+        );
+        let problem = config.problem(&errors).with_instance("/users/7");
+        assert_eq!(problem.title(), "Multiple business errors");
+        assert_eq!(problem.status(), 500);
+        assert_eq!(problem.code(), None);
+        assert_eq!(problem.instance(), Some("/users/7"));
+        assert_eq!(problem.errors().len(), 2);
+        assert_eq!(problem.errors()[0].status(), 400);
+        assert_eq!(problem.errors()[1].detail(), "Permission denied");
+
+        let empty = config.problem(&BizErrors::<AppError>::new());
+        assert_eq!(empty.title(), "No business error");
+        assert_eq!(empty.detail(), "No errors occurred");
+        assert_eq!(empty.status(), 500);
+        assert!(empty.errors().is_empty());
+        assert_eq!(
+            ProblemConfig::new()
+                .biz_problem(&SensitiveError::InvalidValue {
+                    field: "age".to_string(),
+                    value: "secret".to_string(),
+                })
+                .detail(),
+            "<redacted>"
+        );
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_value(&plain).expect("serializable");
+            assert_eq!(
+                json,
+                serde_json::json!({
+                    "type": "about:blank",
+                    "title": "InvalidInput",
+                    "status": 500,
+                    "detail": "Invalid input: email",
+                    "code": 2001,
+                    "fields": { "field": "email" },
+                })
+            );
+            let json = serde_json::to_value(&problem).expect("serializable");
+            assert_eq!(json["instance"], "/users/7");
+            assert_eq!(
+                json["errors"][1]["context"][0]["message"],
+                "Validating profile"
+            );
+        }
+    }
+
+    #[test]
+    fn test_sensitive_sources_are_redacted() {
+        #[derive(BizError, ThisError)]
+        pub enum CredentialError {
+            #[bizcode(4011)]
+            #[error("bad password {password}")]
+            BadPassword {
+                #[biz(sensitive)]
+                password: String,
+            },
+        }
+
+        #[derive(BizError, ThisError)]
+        pub enum LoginError {
+            #[bizcode(4010)]
+            #[error("Login failed")]
+            Failed(#[source] CredentialError),
+        }
+
+        register_biz_error::<CredentialError>();
+
+        let err = LoginError::Failed(CredentialError::BadPassword {
+            password: "hunter2".to_string(),
+        })
+        .with_context("Signing in");
+
+        let links: Vec<_> = err.chain().map(|link| link.message()).collect();
+        assert_eq!(links[2], "<redacted>");
+        assert_eq!(err.error_chain_messages()[2], "<redacted>");
+
+        for report in [
+            err.report().to_string(),
+            err.report().style(Tree).to_string(),
+            err.report().style(Logfmt).to_string(),
+        ] {
+            assert!(report.contains("<redacted>"), "{report}");
+            assert!(!report.contains("hunter2"), "{report}");
+        }
+
+        let problem = ProblemConfig::new().problem(&err);
+        assert_eq!(problem.sources(), ["<redacted>"]);
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&err).expect("serializable");
+            assert!(
+                json.contains(
+                    r#""sources":[{"message":"<redacted>","code":4011,"name":"BadPassword","sensitive":true}]"#
+                ),
+                "{json}"
+            );
+            assert!(!json.contains("hunter2"));
+            let json = serde_json::to_string(&problem).expect("serializable");
+            assert!(!json.contains("hunter2"));
+        }
+
+        #[cfg(feature = "tonic")]
+        {
+            use tonic_types::StatusExt;
+
+            let status = grpc_status(&err);
+            let debug = status.get_details_debug_info().expect("debug info");
+            assert_eq!(
+                debug.stack_entries.last().map(String::as_str),
+                Some("caused by: <redacted>")
+            );
+            assert!(!debug.stack_entries.iter().any(|e| e.contains("hunter2")));
+        }
+    }
 }
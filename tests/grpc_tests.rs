@@ -0,0 +1,348 @@
+#![cfg(feature = "tonic")]
+
+use bizerror::*;
+use thiserror::Error as ThisError;
+use tonic::{
+    Code,
+    Status,
+};
+use tonic_types::StatusExt;
+
+#[derive(BizError, ThisError)]
+#[bizconfig(serde)]
+pub enum OrderError {
+    #[bizcode(4004)]
+    #[bizgrpc(not_found)]
+    #[error("Order {id} not found")]
+    NotFound { id: u64 },
+
+    #[bizcode(4090)]
+    #[bizhttp(409)]
+    #[error("Order already shipped")]
+    AlreadyShipped,
+
+    #[bizcode(4020)]
+    #[bizgrpc(failed_precondition)]
+    #[error("Card {card} declined")]
+    CardDeclined {
+        #[biz(sensitive)]
+        card: String,
+    },
+
+    #[bizcode(8001)]
+    #[error("Inventory unavailable")]
+    InventoryUnavailable(#[source] std::io::Error),
+
+    #[bizcode(4030)]
+    #[error("Coupon {code} expired")]
+    CouponExpired { code: String },
+}
+
+fn get_order(id: u64) -> Result<String, Status> {
+    let error = match id {
+        1 => return Ok("order 1".to_string()),
+        2 => OrderError::AlreadyShipped.with_context("Cancelling order 2"),
+        3 => OrderError::InventoryUnavailable(std::io::Error::other(
+            "warehouse offline",
+        ))
+        .with_context("Reserving stock"),
+        4 => OrderError::CardDeclined {
+            card: "4111111111111111".to_string(),
+        }
+        .with_context("Charging order 4"),
+        id => OrderError::NotFound { id }
+            .with_context("Loading order")
+            .add_context("Handling GetOrder"),
+    };
+    Err(error.into())
+}
+
+// Held by tests reading the process-wide problem config
+static CONFIG_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+#[test]
+fn test_grpc_status() {
+    let _guard = CONFIG_LOCK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    assert!(get_order(1).is_ok());
+
+    // `#[bizgrpc]` sets the code, `ErrorInfo` carries name and code
+    let status = get_order(9).expect_err("");
+    assert_eq!(status.code(), Code::NotFound);
+    assert_eq!(status.message(), "Order 9 not found");
+    let info = status.get_details_error_info().expect("error info");
+    assert_eq!(info.reason, "NotFound");
+    assert_eq!(info.domain, "grpc_tests");
+    assert_eq!(info.metadata["bizerror_code"], "4004");
+    assert_eq!(info.metadata["id"], "9");
+    let debug = status.get_details_debug_info().expect("debug info");
+    assert_eq!(debug.stack_entries.len(), 2);
+    assert!(debug.stack_entries[0].starts_with("context: Loading order, at "));
+
+    // Without `#[bizgrpc]` the code follows the HTTP status
+    assert_eq!(get_order(2).expect_err("").code(), Code::Aborted);
+    let status = get_order(3).expect_err("");
+    assert_eq!(status.code(), Code::Internal);
+    let debug = status.get_details_debug_info().expect("debug info");
+    assert_eq!(debug.stack_entries.len(), 2);
+    assert_eq!(debug.stack_entries[1], "caused by: warehouse offline");
+
+    let status = get_order(4).expect_err("");
+    assert_eq!(status.code(), Code::FailedPrecondition);
+    assert_eq!(status.message(), "<redacted>");
+    let info = status.get_details_error_info().expect("error info");
+    assert_eq!(info.metadata["card"], "<redacted>");
+
+    set_problem_config(
+        ProblemConfig::new()
+            .status_ranges(&[(8000..=8999, 503)], 500)
+            .production(),
+    );
+    let status = get_order(3).expect_err("");
+    assert_eq!(status.code(), Code::Unavailable);
+    assert!(status.get_details_debug_info().is_none());
+    assert!(status.get_details_error_info().is_some());
+    set_problem_config(ProblemConfig::new());
+}
+
+#[test]
+fn test_remote_from_grpc_status() {
+    let _guard = CONFIG_LOCK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let status: Status = OrderError::InventoryUnavailable(
+        std::io::Error::other("warehouse offline"),
+    )
+    .with_context("Reserving stock")
+    .into();
+
+    let remote = RemoteBizError::from_grpc_status(&status).expect("remote");
+    assert_eq!(remote.code(), RemoteCode::Int(8001));
+    assert_eq!(remote.name(), "InventoryUnavailable");
+    assert_eq!(remote.to_string(), "Inventory unavailable");
+    assert_eq!(remote.context()[0].message(), "Reserving stock");
+    assert!(remote.context()[0].location().is_some());
+    assert_eq!(remote.location(), remote.context()[0].location());
+    assert_eq!(remote.sources().collect::<Vec<_>>(), ["warehouse offline"]);
+
+    let status = get_order(9).expect_err("");
+    let remote = RemoteBizError::from_grpc_status(&status).expect("remote");
+    assert_eq!(remote.context().len(), 2);
+    assert_eq!(
+        remote.field("id"),
+        Some(&BizFieldValue::Str("9".to_string()))
+    );
+
+    let status = get_order(4).expect_err("");
+    let remote = RemoteBizError::from_grpc_status(&status).expect("remote");
+    assert!(remote.is_sensitive());
+    assert_eq!(remote.field("card"), Some(&BizFieldValue::Redacted));
+
+    let remote = RemoteBizError::from_grpc_status(&get_order(2).expect_err(""))
+        .expect("remote");
+    assert!(matches!(
+        remote.try_into_typed::<OrderError>(),
+        Ok(OrderError::AlreadyShipped)
+    ));
+
+    // A field named `code` and multi-line sources survive the round trip
+    let status: Status = OrderError::CouponExpired {
+        code: "SPRING".to_string(),
+    }
+    .with_context("Redeeming coupon")
+    .into();
+    let remote = RemoteBizError::from_grpc_status(&status).expect("remote");
+    assert_eq!(remote.code(), RemoteCode::Int(4030));
+    assert_eq!(
+        remote.field("code"),
+        Some(&BizFieldValue::Str("SPRING".to_string()))
+    );
+
+    let status: Status = OrderError::InventoryUnavailable(
+        std::io::Error::other("warehouse offline\nretry at 10:00"),
+    )
+    .with_context("caused by: a full warehouse")
+    .into();
+    let remote = RemoteBizError::from_grpc_status(&status).expect("remote");
+    assert_eq!(remote.context()[0].message(), "caused by: a full warehouse");
+    assert_eq!(remote.sources().collect::<Vec<_>>(), [
+        "warehouse offline\nretry at 10:00"
+    ]);
+
+    // Statuses not built from business errors
+    assert!(
+        RemoteBizError::from_grpc_status(&Status::internal("boom")).is_none()
+    );
+}
+
+#[test]
+fn test_grpc_status_of_plain_and_many_errors() {
+    let status = grpc_status(&OrderError::AlreadyShipped as &dyn DynBizError);
+    assert_eq!(status.code(), Code::Aborted);
+    assert_eq!(status.message(), "Order already shipped");
+    assert!(status.get_details_debug_info().is_none());
+
+    let errors: BizErrors<OrderError> =
+        [OrderError::NotFound { id: 5 }, OrderError::AlreadyShipped]
+            .into_iter()
+            .collect();
+    let status = Status::from(errors);
+    assert_eq!(status.code(), Code::NotFound);
+    assert_eq!(status.message(), "Order 5 not found; Order already shipped");
+    assert_eq!(
+        status.get_details_error_info().expect("error info").reason,
+        "NotFound"
+    );
+}
+
+#[test]
+fn test_grpc_metadata_keys() {
+    #[derive(BizError, ThisError)]
+    pub enum LabelError {
+        #[bizcode(4100)]
+        #[error("Label {0} rejected after {1} tries")]
+        Rejected(String, u32),
+
+        #[bizcode(4101)]
+        #[error("Label taken")]
+        Taken {
+            _owner:        String,
+            bizerror_code: String,
+            field_x:       String,
+            x:             String,
+        },
+    }
+
+    #[derive(Debug, ThisError)]
+    #[error("Price rejected")]
+    struct PriceError;
+
+    impl BizError for PriceError {
+        type CodeType = u32;
+
+        fn code(&self) -> u32 {
+            4102
+        }
+
+        fn name(&self) -> &'static str {
+            "PriceRejected"
+        }
+
+        fn fields(
+            &self,
+        ) -> Vec<(std::borrow::Cow<'static, str>, BizFieldValue)> {
+            vec![
+                ("unit-price".into(), BizFieldValue::Int(3)),
+                ("Größe".into(), BizFieldValue::Int(4)),
+            ]
+        }
+    }
+
+    let valid = |key: &str| {
+        key.len() > 1 &&
+            key.starts_with(|c: char| c.is_ascii_lowercase()) &&
+            key.chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    };
+    let round_trip = |status: Status| {
+        let info = status.get_details_error_info().expect("error info");
+        assert!(info.metadata.keys().all(|key| valid(key)), "{info:?}");
+        let remote = RemoteBizError::from_grpc_status(&status).expect("remote");
+        (info.metadata, remote)
+    };
+
+    let (metadata, remote) = round_trip(
+        LabelError::Rejected("A-1".to_string(), 3)
+            .with_context("Printing")
+            .into(),
+    );
+    assert_eq!(metadata["field_0"], "A-1");
+    assert_eq!(metadata["field_1"], "3");
+    assert_eq!(remote.code(), RemoteCode::Int(4100));
+    assert_eq!(
+        remote.field("0"),
+        Some(&BizFieldValue::Str("A-1".to_string()))
+    );
+    assert_eq!(
+        remote.field("1"),
+        Some(&BizFieldValue::Str("3".to_string()))
+    );
+
+    let (metadata, remote) = round_trip(
+        LabelError::Taken {
+            _owner:        "ops".to_string(),
+            bizerror_code: "shadow".to_string(),
+            field_x:       "escaped".to_string(),
+            x:             "short".to_string(),
+        }
+        .with_context("Claiming label")
+        .into(),
+    );
+    assert_eq!(metadata["bizerror_code"], "4101");
+    assert_eq!(remote.code(), RemoteCode::Int(4101));
+    for (name, value) in [
+        ("_owner", "ops"),
+        ("bizerror_code", "shadow"),
+        ("field_x", "escaped"),
+        ("x", "short"),
+    ] {
+        assert_eq!(
+            remote.field(name),
+            Some(&BizFieldValue::Str(value.to_string())),
+            "{name}"
+        );
+    }
+
+    let (metadata, remote) =
+        round_trip(grpc_status(&PriceError as &dyn DynBizError));
+    assert_eq!(metadata["field_unit-2d-price"], "3");
+    assert_eq!(
+        remote
+            .fields()
+            .iter()
+            .map(|(name, _)| name.as_ref())
+            .collect::<Vec<_>>(),
+        ["Größe", "unit-price"]
+    );
+}
+
+#[test]
+fn test_grpc_string_codes_stay_strings() {
+    #[derive(BizError, ThisError)]
+    #[bizconfig(serde, code_type = "&'static str")]
+    pub enum GatewayError {
+        #[bizcode("404")]
+        #[error("Route missing")]
+        RouteMissing,
+
+        #[bizcode("0")]
+        #[error("Gateway down")]
+        Down,
+    }
+
+    let status: Status = GatewayError::RouteMissing
+        .with_context("Routing request")
+        .into();
+    let info = status.get_details_error_info().expect("error info");
+    assert_eq!(info.metadata["bizerror_code"], "404");
+    assert_eq!(info.metadata["bizerror_code_kind"], "str");
+    let remote = RemoteBizError::from_grpc_status(&status).expect("remote");
+    assert_eq!(remote.code(), RemoteCode::Str("404".into()));
+    assert!(matches!(
+        remote.try_into_typed::<GatewayError>(),
+        Ok(GatewayError::RouteMissing)
+    ));
+
+    let status = grpc_status(&GatewayError::Down as &dyn DynBizError);
+    let remote = RemoteBizError::from_grpc_status(&status).expect("remote");
+    assert!(matches!(
+        remote.try_into_typed::<GatewayError>(),
+        Ok(GatewayError::Down)
+    ));
+
+    // Integer codes say so too
+    let status = get_order(2).expect_err("");
+    let info = status.get_details_error_info().expect("error info");
+    assert_eq!(info.metadata["bizerror_code_kind"], "int");
+}
@@ -0,0 +1,298 @@
+#![cfg(feature = "http")]
+
+use bizerror::*;
+use thiserror::Error as ThisError;
+
+#[derive(BizError, ThisError)]
+pub enum OrderError {
+    #[bizcode(4004)]
+    #[bizhttp(404)]
+    #[error("Order {0} not found")]
+    NotFound(u64),
+
+    #[bizcode(4090)]
+    #[error("Order already shipped")]
+    AlreadyShipped,
+
+    #[bizcode(8001)]
+    #[error("Inventory unavailable")]
+    InventoryUnavailable,
+}
+
+#[cfg(any(feature = "axum", feature = "actix", feature = "tower"))]
+fn load_order(id: u64) -> Result<String, ContextualError<OrderError>> {
+    match id {
+        1 => Ok("order 1".to_string()),
+        2 => Err(OrderError::AlreadyShipped.with_context("Cancelling order 2")),
+        3 => Err(OrderError::InventoryUnavailable
+            .with_context("Reserving stock for order 3")),
+        id => Err(OrderError::NotFound(id).with_context("Loading order")),
+    }
+}
+
+#[cfg(feature = "axum")]
+mod axum_tests {
+    use axum::{
+        Router,
+        body::{
+            Body,
+            to_bytes,
+        },
+        http::{
+            Request,
+            StatusCode,
+            header,
+        },
+        routing::get,
+    };
+    use serde_json::{
+        Value,
+        json,
+    };
+    use tower::ServiceExt;
+
+    use super::*;
+
+    async fn order(
+        axum::extract::Path(id): axum::extract::Path<u64>,
+    ) -> Result<String, BizResponse> {
+        Ok(load_order(id)?)
+    }
+
+    async fn validate() -> Result<String, BizResponse> {
+        let errors: BizErrors<OrderError> =
+            [OrderError::NotFound(5), OrderError::AlreadyShipped]
+                .into_iter()
+                .collect();
+        Err(errors.into())
+    }
+
+    async fn call(uri: &str) -> (StatusCode, Option<String>, Value) {
+        let app = Router::new()
+            .route("/orders/{id}", get(order))
+            .route("/validate", get(validate));
+        let response = app
+            .oneshot(Request::get(uri).body(Body::empty()).expect("request"))
+            .await
+            .expect("infallible");
+        let status = response.status();
+        let content_type = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .map(|value| value.to_str().expect("ascii").to_string());
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let json = serde_json::from_slice(&body).unwrap_or(Value::Null);
+        (status, content_type, json)
+    }
+
+    // One test, since the problem config is process-wide
+    #[tokio::test]
+    async fn test_axum_responses() {
+        let (status, _, _) = call("/orders/1").await;
+        assert_eq!(status, StatusCode::OK);
+
+        // `#[bizhttp]` wins over the default status
+        let (status, content_type, body) = call("/orders/9").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(content_type.as_deref(), Some("application/problem+json"));
+        assert_eq!(body["type"], "about:blank");
+        assert_eq!(body["title"], "NotFound");
+        assert_eq!(body["detail"], "Order 9 not found");
+        assert_eq!(body["code"], 4004);
+        assert_eq!(body["context"][0]["message"], "Loading order");
+
+        // By default 4xxx codes are client errors and the rest server errors
+        let (status, ..) = call("/orders/2").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (status, ..) = call("/orders/3").await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+
+        set_problem_config(
+            ProblemConfig::new()
+                .type_template("https://errors.example.com/{code}")
+                .status_ranges(&[(4000..=4999, 409), (8000..=8999, 503)], 500)
+                .production(),
+        );
+
+        let (status, _, body) = call("/orders/2").await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(
+            body,
+            json!({
+                "type": "https://errors.example.com/4090",
+                "title": "AlreadyShipped",
+                "status": 409,
+                "detail": "Order already shipped",
+                "code": 4090,
+            })
+        );
+
+        let (status, _, body) = call("/orders/3").await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["title"], "InventoryUnavailable");
+
+        let (status, _, body) = call("/orders/9").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert!(body.get("context").is_none());
+
+        let (status, _, body) = call("/validate").await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["title"], "Multiple business errors");
+        assert_eq!(body["errors"][0]["status"], 404);
+        assert_eq!(body["errors"][1]["status"], 409);
+
+        set_problem_config(ProblemConfig::new());
+    }
+}
+
+#[cfg(feature = "actix")]
+mod actix_tests {
+    use actix_web::{
+        App,
+        http::{
+            StatusCode,
+            header,
+        },
+        test,
+        web,
+    };
+    use serde_json::Value;
+
+    use super::*;
+
+    async fn order(id: web::Path<u64>) -> Result<String, BizResponse> {
+        Ok(load_order(id.into_inner())?)
+    }
+
+    #[actix_web::test]
+    async fn test_actix_responses() {
+        let app = test::init_service(
+            App::new().route("/orders/{id}", web::get().to(order)),
+        )
+        .await;
+
+        let request = test::TestRequest::get().uri("/orders/1").to_request();
+        let response = test::call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let request = test::TestRequest::get().uri("/orders/9").to_request();
+        let response = test::call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE),
+            Some(&header::HeaderValue::from_static(
+                "application/problem+json"
+            ))
+        );
+        let body: Value = test::read_body_json(response).await;
+        assert_eq!(body["title"], "NotFound");
+        assert_eq!(body["detail"], "Order 9 not found");
+        assert_eq!(body["code"], 4004);
+        assert_eq!(body["status"], 404);
+    }
+}
+
+#[cfg(feature = "tower")]
+mod tower_tests {
+    use http::{
+        Request,
+        Response,
+        StatusCode,
+        header,
+    };
+    use serde_json::Value;
+    use tower::{
+        Layer,
+        ServiceExt,
+        service_fn,
+    };
+
+    use super::*;
+
+    async fn order(
+        request: Request<()>,
+    ) -> Result<Response<Vec<u8>>, ContextualError<OrderError>> {
+        let id = request.uri().path().trim_start_matches("/orders/");
+        let order = load_order(id.parse().expect("order id"))?;
+        Ok(Response::new(order.into_bytes()))
+    }
+
+    async fn call<S>(service: S, uri: &str) -> Response<Vec<u8>>
+    where
+        S: tower::Service<
+                Request<()>,
+                Response = Response<Vec<u8>>,
+                Error = ContextualError<OrderError>,
+            >,
+    {
+        let request = Request::get(uri).body(()).expect("request");
+        service.oneshot(request).await.expect("error response")
+    }
+
+    #[tokio::test]
+    async fn test_tower_layer() {
+        let service = BizErrorLayer::new()
+            .config(ProblemConfig::new().status(503))
+            .layer(service_fn(order));
+
+        let response = call(service.clone(), "/orders/1").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.body(), b"order 1");
+        assert!(response.extensions().get::<BizErrorInfo>().is_none());
+
+        // `#[bizhttp]` wins over the configured status
+        let response = call(service.clone(), "/orders/9").await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).expect("type"),
+            "application/problem+json"
+        );
+        let info = response.extensions().get::<BizErrorInfo>().expect("info");
+        assert_eq!(info.code(), &BizCode::Int(4004));
+        assert_eq!(info.name(), "NotFound");
+        assert_eq!(info.status(), 404);
+        let body: Value =
+            serde_json::from_slice(response.body()).expect("json");
+        assert_eq!(body["title"], "NotFound");
+        assert_eq!(body["detail"], "Order 9 not found");
+        assert_eq!(body["code"], 4004);
+        assert_eq!(body["context"][0]["message"], "Loading order");
+
+        let response = call(service, "/orders/3").await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let info = response.extensions().get::<BizErrorInfo>().expect("info");
+        assert_eq!(info.name(), "InventoryUnavailable");
+    }
+
+    #[tokio::test]
+    async fn test_tower_layer_boxed_errors() {
+        let service = BizErrorLayer::new()
+            .config(ProblemConfig::new().status(409))
+            .layer(service_fn(|request: Request<()>| async move {
+                let error: Box<dyn DynBizError> =
+                    if request.uri().path() == "/ship" {
+                        OrderError::AlreadyShipped.into()
+                    } else {
+                        OrderError::NotFound(7).into()
+                    };
+                Err::<Response<Vec<u8>>, _>(error)
+            }));
+
+        let request = Request::get("/ship").body(()).expect("request");
+        let response = service
+            .clone()
+            .oneshot(request)
+            .await
+            .expect("error response");
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        let info = response.extensions().get::<BizErrorInfo>().expect("info");
+        assert_eq!(info.code(), &BizCode::Int(4090));
+        assert_eq!(info.name(), "AlreadyShipped");
+
+        let request = Request::get("/other").body(()).expect("request");
+        let response = service.oneshot(request).await.expect("error response");
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,50 @@
+use bizerror::BizError;
+
+#[derive(BizError, thiserror::Error)]
+#[bizconfig(code_type = "u8", auto_start = 250, auto_increment = 3)]
+pub enum Overflow {
+    #[error("A")]
+    A,
+
+    #[error("B")]
+    B,
+
+    #[error("C")]
+    C,
+}
+
+#[derive(BizError, thiserror::Error)]
+#[bizconfig(code_type = "u32", auto_start = -1)]
+pub enum NegativeUnsigned {
+    #[error("A")]
+    A,
+}
+
+#[derive(BizError, thiserror::Error)]
+#[bizconfig(code_type = "usize", auto_start = 4294967296)]
+pub enum BeyondPointerWidth {
+    #[error("A")]
+    A,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StatusCode(u16);
+
+impl std::fmt::Display for StatusCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(BizError, thiserror::Error)]
+#[bizconfig(code_type = "StatusCode")]
+pub enum CustomType {
+    #[bizcode(StatusCode(404))]
+    #[error("A")]
+    A,
+
+    #[error("B")]
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+use bizerror::BizError;
+
+#[derive(BizError, thiserror::Error)]
+#[bizconfig(auto_start = 100)]
+#[bizconfig(auto_increment = 10)]
+pub enum DuplicateConfig {
+    #[error("A")]
+    A,
+}
+
+#[derive(BizError, thiserror::Error)]
+pub enum DuplicateCode {
+    #[bizcode(1)]
+    #[bizcode(2)]
+    #[error("A")]
+    A,
+}
+
+#[derive(BizError, thiserror::Error)]
+#[bizconfig(auto_start = 1, auto_start = 2)]
+pub enum DuplicateParameter {
+    #[error("A")]
+    A,
+}
+
+#[derive(BizError, thiserror::Error)]
+pub enum DuplicateHttpStatus {
+    #[bizhttp(404)]
+    #[bizhttp(410)]
+    #[error("A")]
+    A,
+}
+
+#[derive(BizError, thiserror::Error)]
+pub enum DuplicateGrpcCode {
+    #[bizgrpc(not_found)]
+    #[bizgrpc(aborted)]
+    #[error("A")]
+    A,
+}
+
+fn main() {}
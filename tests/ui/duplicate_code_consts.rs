@@ -0,0 +1,14 @@
+use bizerror::BizError;
+
+#[derive(BizError, thiserror::Error)]
+pub enum CollidingConsts {
+    #[bizcode(1)]
+    #[error("A")]
+    HttpError,
+
+    #[bizcode(2)]
+    #[error("B")]
+    HTTPError,
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+use bizerror::BizError;
+
+#[derive(BizError, thiserror::Error)]
+pub enum InnerError {
+    #[error("A")]
+    A,
+}
+
+#[derive(BizError, thiserror::Error)]
+#[bizmap(from = InnerError, A => Missing)]
+pub enum UnknownTarget {
+    #[error("B")]
+    B(#[source] InnerError),
+}
+
+#[derive(BizError, thiserror::Error)]
+#[bizmap(from = InnerError, _ => Unit)]
+pub enum UnitTarget {
+    #[error("Unit")]
+    Unit,
+}
+
+#[derive(BizError, thiserror::Error)]
+#[bizmap(source = InnerError, _ => B)]
+pub enum MissingFrom {
+    #[error("B")]
+    B(#[source] InnerError),
+}
+
+fn main() {}
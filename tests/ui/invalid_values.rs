@@ -0,0 +1,122 @@
+use bizerror::BizError;
+
+#[derive(BizError, thiserror::Error)]
+#[bizconfig(auto_strat = 100)]
+pub enum Typo {
+    #[error("A")]
+    A,
+}
+
+#[derive(BizError, thiserror::Error)]
+#[bizconfig(code_type = u16)]
+pub enum UnquotedCodeType {
+    #[error("A")]
+    A,
+}
+
+#[derive(BizError, thiserror::Error)]
+#[bizconfig(code_type = "not a type")]
+pub enum InvalidCodeType {
+    #[error("A")]
+    A,
+}
+
+#[derive(BizError, thiserror::Error)]
+#[bizconfig(serde = true)]
+pub enum SerdeWithValue {
+    #[error("A")]
+    A,
+}
+
+#[derive(BizError, thiserror::Error)]
+pub enum EmptyCode {
+    #[bizcode()]
+    #[error("A")]
+    A,
+}
+
+#[derive(BizError, thiserror::Error)]
+pub enum NameValueCode {
+    #[bizcode = 5]
+    #[error("A")]
+    A,
+}
+
+#[derive(BizError, thiserror::Error)]
+pub enum FieldTypo {
+    #[error("A")]
+    A {
+        #[biz(sensitiv)]
+        secret: String,
+    },
+}
+
+#[derive(BizError, thiserror::Error)]
+pub enum InvalidHttpStatus {
+    #[bizhttp(700)]
+    #[error("A")]
+    A,
+}
+
+#[derive(BizError, thiserror::Error)]
+pub enum NonNumericHttpStatus {
+    #[bizhttp(not_found)]
+    #[error("A")]
+    A,
+}
+
+#[derive(BizError, thiserror::Error)]
+pub enum UnknownGrpcCode {
+    #[bizgrpc(not_fuond)]
+    #[error("A")]
+    A,
+}
+
+#[derive(BizError, thiserror::Error)]
+pub enum NumericGrpcCode {
+    #[bizgrpc(404)]
+    #[error("A")]
+    A,
+}
+
+#[derive(BizError, thiserror::Error)]
+#[bizconfig(name_case = "snake-case")]
+pub enum UnknownNameCase {
+    #[error("A")]
+    A,
+}
+
+#[derive(BizError, thiserror::Error)]
+#[bizconfig(severity = "fatal")]
+pub enum UnknownSeverity {
+    #[error("A")]
+    A,
+}
+
+#[derive(BizError, thiserror::Error)]
+pub enum UnknownVariantSeverity {
+    #[bizseverity(warn)]
+    #[error("A")]
+    A,
+}
+
+#[derive(BizError, thiserror::Error)]
+#[bizconfig(doc_url = "https://errors.example.com/{id}")]
+pub enum UnknownDocUrlPlaceholder {
+    #[error("A")]
+    A,
+}
+
+const fn base() -> u32 {
+    4000
+}
+
+#[derive(BizError, thiserror::Error)]
+#[bizconfig(doc_url = "https://errors.example.com/{code}")]
+pub enum DocUrlWithExpressionCode {
+    #[bizcode(base() + 1)]
+    #[error("A")]
+    A,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use bizerror::BizError;
+
+#[derive(BizError, thiserror::Error)]
+#[bizcode(100)]
+pub enum CodeOnEnum {
+    #[error("A")]
+    A,
+}
+
+#[derive(BizError, thiserror::Error)]
+pub enum ConfigOnVariant {
+    #[bizconfig(auto_start = 1)]
+    #[error("A")]
+    A,
+
+    #[error("B: {0}")]
+    B(#[bizcode(2)] u32),
+
+    #[biz(sensitive)]
+    #[error("C")]
+    C,
+}
+
+fn main() {}